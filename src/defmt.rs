@@ -0,0 +1,50 @@
+use defmt::{Format, Formatter};
+
+use crate::{BoundaryError, Error, SliceString};
+
+impl Format for SliceString<'_> {
+    fn format(&self, fmt: Formatter<'_>) {
+        defmt::write!(fmt, "{=str}", self.as_str())
+    }
+}
+
+impl Format for Error {
+    fn format(&self, fmt: Formatter<'_>) {
+        defmt::write!(
+            fmt,
+            "SliceString full: needed {=usize}, available {=usize}",
+            self.needed,
+            self.available
+        )
+    }
+}
+
+impl Format for BoundaryError {
+    fn format(&self, fmt: Formatter<'_>) {
+        defmt::write!(
+            fmt,
+            "SliceString index {=usize} out of bounds or not at a character boundary",
+            self.index
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BoundaryError, Error, SliceString};
+
+    fn assert_format<T: defmt::Format>(_: &T) {}
+
+    #[test]
+    fn format() {
+        let mut buf = [0u8; 4];
+        let s = SliceString::new(&mut buf[..]);
+        assert_format(&s);
+    }
+
+    #[test]
+    fn format_error() {
+        assert_format(&Error::default());
+        assert_format(&BoundaryError::default());
+    }
+}
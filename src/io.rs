@@ -0,0 +1,345 @@
+#[cfg(any(feature = "std", feature = "embedded-io"))]
+use crate::SliceString;
+
+/// A byte oriented writer appending UTF-8 data to a [`SliceString`].
+///
+/// Each chunk written must be valid UTF-8. A `char` encoding must not be split
+/// across chunks. Data is appended up to the last character boundary that fits.
+/// Once the string is full, writes return `Ok(0)`.
+///
+/// With the `std` feature this implements `std::io::Write` and
+/// with the `embedded-io` feature it implements `embedded_io::Write`.
+/// The latter is also implemented by [`SliceString`] itself.
+#[cfg(any(feature = "std", feature = "embedded-io"))]
+pub struct SliceStringWriter<'s, 'a>(&'s mut SliceString<'a>);
+
+#[cfg(any(feature = "std", feature = "embedded-io"))]
+impl<'s, 'a> SliceStringWriter<'s, 'a> {
+    /// Create a new writer appending to `string`.
+    pub fn new(string: &'s mut SliceString<'a>) -> Self {
+        Self(string)
+    }
+
+    /// Return the underlying string.
+    pub fn into_inner(self) -> &'s mut SliceString<'a> {
+        self.0
+    }
+
+    /// Append the longest valid UTF-8 prefix of `buf` that fits.
+    ///
+    /// Returns the number of bytes appended or the UTF-8 error if `buf`
+    /// does not start with a complete `char`.
+    fn write_utf8(&mut self, buf: &[u8]) -> Result<usize, core::str::Utf8Error> {
+        let s = match core::str::from_utf8(buf) {
+            Ok(s) => s,
+            Err(e) if e.valid_up_to() > 0 => {
+                // `buf[..valid_up_to]` has been validated
+                unsafe { core::str::from_utf8_unchecked(&buf[..e.valid_up_to()]) }
+            }
+            Err(e) => return Err(e),
+        };
+        Ok(self.0.push_str_lossy(s))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'s, 'a> std::io::Write for SliceStringWriter<'s, 'a> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.write_utf8(buf)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(feature = "embedded-io")]
+impl<'s, 'a> embedded_io::ErrorType for SliceStringWriter<'s, 'a> {
+    type Error = embedded_io::ErrorKind;
+}
+
+#[cfg(feature = "embedded-io")]
+impl<'s, 'a> embedded_io::Write for SliceStringWriter<'s, 'a> {
+    fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> {
+        match self.write_utf8(buf) {
+            Ok(0) if !buf.is_empty() => Err(embedded_io::ErrorKind::WriteZero),
+            Ok(n) => Ok(n),
+            Err(_) => Err(embedded_io::ErrorKind::InvalidData),
+        }
+    }
+
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+#[cfg(feature = "embedded-io")]
+impl<'a> embedded_io::ErrorType for SliceString<'a> {
+    type Error = embedded_io::ErrorKind;
+}
+
+/// Append UTF-8 data like [`SliceStringWriter`].
+#[cfg(feature = "embedded-io")]
+impl<'a> embedded_io::Write for SliceString<'a> {
+    fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> {
+        embedded_io::Write::write(&mut SliceStringWriter::new(self), buf)
+    }
+
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+/// A reader over the content of a [`SliceString`](crate::SliceString).
+///
+/// The reader tracks a byte position. Bytes, `char`s, and `str`s can be read
+/// without copying and the position can be changed freely.
+/// A position beyond the end reads nothing.
+///
+/// With the `std` feature this implements `std::io::Read` and `std::io::Seek`
+/// and with the `embedded-io` feature it implements `embedded_io::Read` and `embedded_io::Seek`.
+///
+/// ```
+/// # use slice_string::{SliceString, SliceStringReader};
+/// let mut buf = *b"T=21.5";
+/// let s = SliceString::try_from(&mut buf[..]).unwrap();
+/// let mut r = SliceStringReader::new(&s);
+/// assert_eq!(r.read_char(), Some('T'));
+/// assert_eq!(r.read_char(), Some('='));
+/// assert_eq!(r.read_str(8), "21.5");
+/// assert_eq!(r.read_char(), None);
+/// ```
+#[derive(Clone, Debug)]
+pub struct SliceStringReader<'s> {
+    string: &'s str,
+    pos: usize,
+}
+
+impl<'s> SliceStringReader<'s> {
+    /// Create a new reader starting at the beginning of `string`.
+    pub fn new(string: &'s str) -> Self {
+        Self { string, pos: 0 }
+    }
+
+    /// Return the number of bytes consumed.
+    pub fn position(&self) -> usize {
+        self.pos
+    }
+
+    /// Set the byte position.
+    pub fn set_position(&mut self, pos: usize) {
+        self.pos = pos;
+    }
+
+    /// Return the unread bytes.
+    pub fn remaining(&self) -> &'s [u8] {
+        self.string.as_bytes().get(self.pos..).unwrap_or_default()
+    }
+
+    /// Read the next `char`.
+    ///
+    /// Returns `None` at the end or if the position is not at a character boundary.
+    pub fn read_char(&mut self) -> Option<char> {
+        let ch = self.string.get(self.pos..)?.chars().next()?;
+        self.pos += ch.len_utf8();
+        Some(ch)
+    }
+
+    /// Read up to `n_bytes` bytes truncated to the last character boundary.
+    ///
+    /// Returns an empty `str` at the end or if the position is not at a character boundary.
+    pub fn read_str(&mut self, n_bytes: usize) -> &'s str {
+        let Some(rest) = self.string.get(self.pos..) else {
+            return "";
+        };
+        let s = &rest[..crate::floor_char_boundary(rest, n_bytes)];
+        self.pos += s.len();
+        s
+    }
+
+    /// Copy unread bytes into `buf` and return the number of bytes copied.
+    #[cfg(any(feature = "std", feature = "embedded-io"))]
+    fn read_bytes(&mut self, buf: &mut [u8]) -> usize {
+        let src = self.remaining();
+        let n = buf.len().min(src.len());
+        buf[..n].copy_from_slice(&src[..n]);
+        self.pos += n;
+        n
+    }
+
+    /// Move to byte position `pos` and return it or `None` if it is out of range.
+    #[cfg(any(feature = "std", feature = "embedded-io"))]
+    fn seek_to(&mut self, pos: u64) -> Option<u64> {
+        self.pos = usize::try_from(pos).ok()?;
+        Some(pos)
+    }
+
+    /// Move to `offset` bytes from `base` and return the new position
+    /// or `None` if it would be negative.
+    #[cfg(any(feature = "std", feature = "embedded-io"))]
+    fn seek_from(&mut self, base: usize, offset: i64) -> Option<u64> {
+        self.seek_to((base as u64).checked_add_signed(offset)?)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'s> std::io::Read for SliceStringReader<'s> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        Ok(self.read_bytes(buf))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'s> std::io::Seek for SliceStringReader<'s> {
+    fn seek(&mut self, pos: std::io::SeekFrom) -> std::io::Result<u64> {
+        match pos {
+            std::io::SeekFrom::Start(n) => self.seek_to(n),
+            std::io::SeekFrom::End(n) => self.seek_from(self.string.len(), n),
+            std::io::SeekFrom::Current(n) => self.seek_from(self.pos, n),
+        }
+        .ok_or_else(|| std::io::Error::from(std::io::ErrorKind::InvalidInput))
+    }
+}
+
+#[cfg(feature = "embedded-io")]
+impl<'s> embedded_io::ErrorType for SliceStringReader<'s> {
+    type Error = embedded_io::ErrorKind;
+}
+
+#[cfg(feature = "embedded-io")]
+impl<'s> embedded_io::Read for SliceStringReader<'s> {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+        Ok(self.read_bytes(buf))
+    }
+}
+
+#[cfg(feature = "embedded-io")]
+impl<'s> embedded_io::Seek for SliceStringReader<'s> {
+    fn seek(&mut self, pos: embedded_io::SeekFrom) -> Result<u64, Self::Error> {
+        match pos {
+            embedded_io::SeekFrom::Start(n) => self.seek_to(n),
+            embedded_io::SeekFrom::End(n) => self.seek_from(self.string.len(), n),
+            embedded_io::SeekFrom::Current(n) => self.seek_from(self.pos, n),
+        }
+        .ok_or(embedded_io::ErrorKind::InvalidInput)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn io_write() {
+        use std::io::{ErrorKind, Write};
+
+        let mut buf = [0; 6];
+        let mut s = SliceString::new(&mut buf[..]);
+        let mut w = SliceStringWriter::new(&mut s);
+        write!(w, "a{}", 1).unwrap();
+        w.write_all("ä".as_bytes()).unwrap();
+        assert_eq!(
+            w.write("ä".as_bytes()[..1].as_ref()).unwrap_err().kind(),
+            ErrorKind::InvalidData
+        );
+        assert_eq!(w.write(b"b\xff").unwrap(), 1);
+        assert_eq!(w.write("€".as_bytes()).unwrap(), 0);
+        assert_eq!(
+            w.write_all("€".as_bytes()).unwrap_err().kind(),
+            ErrorKind::WriteZero
+        );
+        w.write_all(b"c").unwrap();
+        assert_eq!(w.write(b"d").unwrap(), 0);
+        w.flush().unwrap();
+        assert_eq!(w.into_inner(), "a1äbc");
+    }
+
+    #[test]
+    #[cfg(feature = "embedded-io")]
+    fn embedded_io_write() {
+        use embedded_io::{ErrorKind, Write};
+
+        let mut buf = [0; 4];
+        let mut s = SliceString::new(&mut buf[..]);
+        s.write_all("aä".as_bytes()).unwrap();
+        assert_eq!(s.write(&[0xc3]), Err(ErrorKind::InvalidData));
+        assert_eq!(s.write("€".as_bytes()), Err(ErrorKind::WriteZero));
+        assert_eq!(s.write(b""), Ok(0));
+        let mut w = SliceStringWriter::new(&mut s);
+        assert_eq!(w.write(b"bc"), Ok(1));
+        assert_eq!(w.write(b"c"), Err(ErrorKind::WriteZero));
+        w.flush().unwrap();
+        assert_eq!(s, "aäb");
+    }
+
+    #[test]
+    #[cfg(feature = "embedded-io")]
+    fn embedded_io_read() {
+        use embedded_io::Read;
+
+        let mut b = "aäb".as_bytes().to_owned();
+        let s = SliceString::try_from(&mut b[..]).unwrap();
+        let mut r = SliceStringReader::new(&s);
+        let mut buf = [0; 2];
+        assert_eq!(r.read(&mut buf), Ok(2));
+        assert_eq!(&buf, b"a\xc3");
+        assert_eq!(r.position(), 2);
+        assert_eq!(r.remaining(), b"\xa4b");
+        r.read_exact(&mut buf).unwrap();
+        assert_eq!(r.read(&mut buf), Ok(0));
+    }
+
+    #[test]
+    #[cfg(feature = "embedded-io")]
+    fn embedded_io_seek() {
+        use embedded_io::{ErrorKind, Seek, SeekFrom};
+
+        let mut r = SliceStringReader::new("aäb");
+        assert_eq!(r.seek(SeekFrom::End(-1)), Ok(3));
+        assert_eq!(r.read_char(), Some('b'));
+        assert_eq!(r.seek(SeekFrom::Current(-3)), Ok(1));
+        assert_eq!(r.read_char(), Some('ä'));
+        assert_eq!(r.seek(SeekFrom::Start(9)), Ok(9));
+        assert_eq!(r.seek(SeekFrom::Current(-10)), Err(ErrorKind::InvalidInput));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn io_read_seek() {
+        use std::io::{ErrorKind, Read, Seek, SeekFrom};
+
+        let mut r = SliceStringReader::new("aäb");
+        let mut s = String::new();
+        r.read_to_string(&mut s).unwrap();
+        assert_eq!(s, "aäb");
+        assert_eq!(r.seek(SeekFrom::Start(1)).unwrap(), 1);
+        assert_eq!(r.read_str(3), "äb");
+        assert_eq!(
+            r.seek(SeekFrom::End(-5)).unwrap_err().kind(),
+            ErrorKind::InvalidInput
+        );
+        assert_eq!(r.stream_position().unwrap(), 4);
+    }
+
+    #[test]
+    fn reader() {
+        let mut r = SliceStringReader::new("aä€b");
+        assert_eq!(r.read_str(0), "");
+        assert_eq!(r.read_str(2), "a");
+        assert_eq!(r.read_char(), Some('ä'));
+        assert_eq!(r.read_str(2), "");
+        assert_eq!(r.read_str(3), "€");
+        assert_eq!(r.position(), 6);
+        assert_eq!(r.remaining(), b"b");
+        r.set_position(2);
+        assert_eq!(r.read_char(), None);
+        assert_eq!(r.read_str(4), "");
+        assert_eq!(r.remaining(), &"ä€b".as_bytes()[1..]);
+        r.set_position(10);
+        assert_eq!(r.read_char(), None);
+        assert_eq!(r.read_str(4), "");
+        assert_eq!(r.remaining(), b"");
+    }
+}
@@ -0,0 +1,185 @@
+use core::{iter::FusedIterator, str};
+
+use crate::SliceString;
+
+/// A draining iterator over the `char`s of a byte range of a [`SliceString`].
+///
+/// This is created by [`SliceString::drain`].
+pub struct Drain<'s, 'a> {
+    string: &'s mut SliceString<'a>,
+    start: usize,
+    end: usize,
+    // Remaining undrained byte range `front..back` within `start..end`.
+    front: usize,
+    back: usize,
+}
+
+impl<'s, 'a> Drain<'s, 'a> {
+    pub(crate) fn new(string: &'s mut SliceString<'a>, start: usize, end: usize) -> Self {
+        Self {
+            string,
+            start,
+            end,
+            front: start,
+            back: end,
+        }
+    }
+
+    /// Return the remaining (not yet yielded) part of the drained range.
+    pub fn as_str(&self) -> &str {
+        &self.string[self.front..self.back]
+    }
+}
+
+impl<'s, 'a> Iterator for Drain<'s, 'a> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        let ch = self.as_str().chars().next()?;
+        self.front += ch.len_utf8();
+        Some(ch)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.as_str().chars().size_hint()
+    }
+}
+
+impl<'s, 'a> DoubleEndedIterator for Drain<'s, 'a> {
+    fn next_back(&mut self) -> Option<char> {
+        let ch = self.as_str().chars().next_back()?;
+        self.back -= ch.len_utf8();
+        Some(ch)
+    }
+}
+
+impl<'s, 'a> FusedIterator for Drain<'s, 'a> {}
+
+impl<'s, 'a> Drop for Drain<'s, 'a> {
+    fn drop(&mut self) {
+        self.string.remove_bytes(self.start, self.end);
+    }
+}
+
+/// An iterator over the lines of a [`SliceString`].
+///
+/// This is created by [`SliceString::lines`].
+#[derive(Clone, Debug)]
+pub struct Lines<'s>(str::Lines<'s>);
+
+impl<'s> Lines<'s> {
+    pub(crate) fn new(s: &'s str) -> Self {
+        Self(s.lines())
+    }
+}
+
+impl<'s> Iterator for Lines<'s> {
+    type Item = &'s str;
+
+    fn next(&mut self) -> Option<&'s str> {
+        self.0.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<'s> DoubleEndedIterator for Lines<'s> {
+    fn next_back(&mut self) -> Option<&'s str> {
+        self.0.next_back()
+    }
+}
+
+impl<'s> FusedIterator for Lines<'s> {}
+
+/// An iterator over the ASCII whitespace separated words of a [`SliceString`].
+///
+/// This is created by [`SliceString::words`].
+#[derive(Clone, Debug)]
+pub struct Words<'s>(str::SplitAsciiWhitespace<'s>);
+
+impl<'s> Words<'s> {
+    pub(crate) fn new(s: &'s str) -> Self {
+        Self(s.split_ascii_whitespace())
+    }
+}
+
+impl<'s> Iterator for Words<'s> {
+    type Item = &'s str;
+
+    fn next(&mut self) -> Option<&'s str> {
+        self.0.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<'s> DoubleEndedIterator for Words<'s> {
+    fn next_back(&mut self) -> Option<&'s str> {
+        self.0.next_back()
+    }
+}
+
+impl<'s> FusedIterator for Words<'s> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::SliceString;
+
+    #[test]
+    fn drain() {
+        let mut b = "aé€𝄞z".as_bytes().to_owned();
+        let mut s = SliceString::try_from(&mut b[..]).unwrap();
+        let mut d = s.drain(1..10);
+        assert_eq!(d.next(), Some('é'));
+        assert_eq!(d.next_back(), Some('𝄞'));
+        assert_eq!(d.as_str(), "€");
+        drop(d);
+        assert_eq!(s, "az");
+        assert!(s.drain(..).eq("az".chars()));
+        assert!(s.is_empty());
+    }
+
+    #[test]
+    fn drain_unconsumed() {
+        let mut b = "foo bar".as_bytes().to_owned();
+        let mut s = SliceString::try_from(&mut b[..]).unwrap();
+        s.drain(..=3);
+        assert_eq!(s, "bar");
+        s.drain(3..);
+        assert_eq!(s, "bar");
+    }
+
+    #[test]
+    #[should_panic]
+    fn panic_drain() {
+        let mut b = "é".as_bytes().to_owned();
+        let mut s = SliceString::try_from(&mut b[..]).unwrap();
+        s.drain(1..);
+    }
+
+    #[test]
+    fn lines() {
+        let mut b = "a\nb\r\n\nc".as_bytes().to_owned();
+        let mut s = SliceString::try_from(&mut b[..]).unwrap();
+        assert!(s.lines().eq(["a", "b", "", "c"]));
+        assert_eq!(s.lines().next_back(), Some("c"));
+        assert_eq!(s.lines_count(), 4);
+        s.clear();
+        assert_eq!(s.lines_count(), 0);
+    }
+
+    #[test]
+    fn words() {
+        let mut b = *b" a\tbc  d\n";
+        let s = SliceString::try_from(&mut b[..]).unwrap();
+        assert!(s.words().eq(["a", "bc", "d"]));
+        assert!(s.words().rev().eq(["d", "bc", "a"]));
+        let mut b = *b" \r\n ";
+        let s = SliceString::try_from(&mut b[..]).unwrap();
+        assert_eq!(s.words().next(), None);
+    }
+}
@@ -6,17 +6,259 @@
 
 //! This module implements support for a String-like structure that is backed by a slice.
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
 #[cfg(feature = "ufmt-impl")]
 mod ufmt;
 
-use core::{fmt, hash, ops, str};
+#[cfg(feature = "serde")]
+mod serde;
+
+#[cfg(feature = "defmt")]
+mod defmt;
+
+mod io;
+pub use io::SliceStringReader;
+#[cfg(any(feature = "std", feature = "embedded-io"))]
+pub use io::SliceStringWriter;
+
+mod iter;
+pub use iter::{Drain, Lines, Words};
+
+mod stack;
+pub use stack::StackString;
+
+mod static_str;
+pub use static_str::StaticSliceStr;
+
+use core::{borrow, char, ffi::CStr, fmt, hash, ops, slice, str};
 pub use tinyvec;
 use tinyvec::SliceVec; // re-export
 
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+/// Error returned when an operation would exceed the capacity of a [`SliceString`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Error {
+    /// The number of bytes the operation needed.
+    pub needed: usize,
+    /// The number of bytes that were available.
+    pub available: usize,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "SliceString full: needed {}, available {}",
+            self.needed, self.available
+        )
+    }
+}
+
+impl core::error::Error for Error {}
+
+/// Error returned by [`SliceString::from_cstr`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum FromCStrError {
+    /// The buffer is too short.
+    Capacity(Error),
+    /// The data is not valid UTF-8.
+    Utf8(str::Utf8Error),
+}
+
+impl From<Error> for FromCStrError {
+    fn from(value: Error) -> Self {
+        Self::Capacity(value)
+    }
+}
+
+impl From<str::Utf8Error> for FromCStrError {
+    fn from(value: str::Utf8Error) -> Self {
+        Self::Utf8(value)
+    }
+}
+
+impl fmt::Display for FromCStrError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Capacity(err) => err.fmt(f),
+            Self::Utf8(err) => err.fmt(f),
+        }
+    }
+}
+
+impl core::error::Error for FromCStrError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::Capacity(err) => Some(err),
+            Self::Utf8(err) => Some(err),
+        }
+    }
+}
+
+/// Error returned by [`SliceString::from_utf16`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FromUtf16Error {
+    /// The buffer is too short.
+    Capacity(Error),
+    /// The data is not valid UTF-16.
+    Utf16(char::DecodeUtf16Error),
+}
+
+impl From<Error> for FromUtf16Error {
+    fn from(value: Error) -> Self {
+        Self::Capacity(value)
+    }
+}
+
+impl From<char::DecodeUtf16Error> for FromUtf16Error {
+    fn from(value: char::DecodeUtf16Error) -> Self {
+        Self::Utf16(value)
+    }
+}
+
+impl fmt::Display for FromUtf16Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Capacity(err) => err.fmt(f),
+            Self::Utf16(err) => err.fmt(f),
+        }
+    }
+}
+
+impl core::error::Error for FromUtf16Error {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::Capacity(err) => Some(err),
+            Self::Utf16(err) => Some(err),
+        }
+    }
+}
+
+/// Error returned when an index is out of bounds or not at a character boundary.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct BoundaryError {
+    /// The offending byte index.
+    pub index: usize,
+}
+
+impl fmt::Display for BoundaryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "SliceString index {} out of bounds or not at a character boundary",
+            self.index
+        )
+    }
+}
+
+impl core::error::Error for BoundaryError {}
+
+/// A pattern matching individual `char`s.
+///
+/// This mirrors the `char` based implementors of `core::str::pattern::Pattern`
+/// which cannot be named on stable Rust.
+pub trait CharPattern {
+    /// Return whether the `char` matches the pattern.
+    fn matches(&mut self, c: char) -> bool;
+}
+
+impl CharPattern for char {
+    fn matches(&mut self, c: char) -> bool {
+        *self == c
+    }
+}
+
+impl CharPattern for &[char] {
+    fn matches(&mut self, c: char) -> bool {
+        self.contains(&c)
+    }
+}
+
+impl<const N: usize> CharPattern for [char; N] {
+    fn matches(&mut self, c: char) -> bool {
+        self.contains(&c)
+    }
+}
+
+impl<const N: usize> CharPattern for &[char; N] {
+    fn matches(&mut self, c: char) -> bool {
+        self.contains(&c)
+    }
+}
+
+impl<F: FnMut(char) -> bool> CharPattern for F {
+    fn matches(&mut self, c: char) -> bool {
+        self(c)
+    }
+}
+
+/// A fixed capacity string buffer.
+///
+/// This is implemented by the borrowing [`SliceString`] and the owning [`StackString`]
+/// and allows generic code to accept either.
+/// Unlike the inherent methods with the same names, `push` and `push_str`
+/// return an error instead of panicking if the remaining space is insufficient.
+///
+/// ```
+/// # use slice_string::{Error, SliceString, StackString, StringBuf};
+/// fn topic<S: StringBuf>(s: &mut S, id: &str) -> Result<(), Error> {
+///     s.clear();
+///     s.push_str("dt/")?;
+///     s.push_str(id)
+/// }
+/// let mut a = StackString::<16>::new();
+/// topic(&mut a, "ab").unwrap();
+/// let mut buf = [0; 4];
+/// let mut b = SliceString::new(&mut buf[..]);
+/// assert!(topic(&mut b, "ab").is_err());
+/// ```
+pub trait StringBuf: AsRef<str> + AsMut<str> + fmt::Write {
+    /// Append a `char` or return an error if the remaining space is insufficient.
+    fn push(&mut self, c: char) -> Result<(), Error>;
+
+    /// Append a `str` or return an error if the remaining space is insufficient.
+    fn push_str(&mut self, string: &str) -> Result<(), Error>;
+
+    /// Set the current string length to zero.
+    fn clear(&mut self);
+
+    /// Return the maximum number of bytes the string can contain.
+    fn capacity(&self) -> usize;
+
+    /// Return the number of bytes that can still be appended.
+    fn remaining_capacity(&self) -> usize {
+        self.capacity() - self.as_ref().len()
+    }
+}
+
 /// A UTF-8-encoded growable string backed by a `u8` slice.
 ///
 /// This supports some of the API from `std::String` and dereferences
 /// to `core::str`.
+///
+/// The non-mutating `str` methods are available through `Deref`, including
+/// those generic over a pattern. See [`SliceString::strip_prefix_in_place`]
+/// for in-place variants.
+///
+/// ```
+/// # use slice_string::SliceString;
+/// let mut buf = *b"dt/device/settings";
+/// let topic = SliceString::try_from(&mut buf[..]).unwrap();
+/// assert_eq!(topic.strip_prefix("dt/"), Some("device/settings"));
+/// assert_eq!(topic.strip_suffix('s'), Some("dt/device/setting"));
+/// assert_eq!(topic.split_once('/'), Some(("dt", "device/settings")));
+/// assert_eq!(topic.rsplit_once('/'), Some(("dt/device", "settings")));
+/// ```
+///
+/// `SliceString` is `Send` and `Sync` since it only holds a `&mut [u8]` and a length.
+/// No `unsafe impl` is involved. To share one between an interrupt handler and
+/// the main context, wrap it in a critical section mutex like any other `&mut` data.
 #[repr(transparent)]
 #[derive(Default)]
 pub struct SliceString<'a>(SliceVec<'a, u8>);
@@ -55,6 +297,68 @@ impl<'a> SliceString<'a> {
         Self::new_unchecked(SliceVec::from_slice_len(buf, len))
     }
 
+    /// Create a new `SliceString` from a mutable slice and copy a `str` into it.
+    ///
+    /// Returns an error if the slice is too short to hold the `str`.
+    pub fn try_from_str(src: &str, buf: &'a mut [u8]) -> Result<Self, Error> {
+        let mut s = Self::new(buf);
+        s.check_capacity(src.len())?;
+        s.push_str(src);
+        Ok(s)
+    }
+
+    /// Copy `src` into `buf` and return a `SliceString` holding the copy.
+    ///
+    /// This is the same as [`SliceString::try_from_str`].
+    /// Returns an error if `buf` is shorter than `src`.
+    ///
+    /// ```
+    /// # use slice_string::SliceString;
+    /// let mut buf = [0; 16];
+    /// let mut topic = SliceString::from_str_in_place("dt/", &mut buf[..]).unwrap();
+    /// topic.push_str("device");
+    /// assert_eq!(topic, "dt/device");
+    /// ```
+    pub fn from_str_in_place(src: &str, buf: &'a mut [u8]) -> Result<Self, Error> {
+        Self::try_from_str(src, buf)
+    }
+
+    /// Create a new `SliceString` from a mutable slice and copy a `CStr` into it.
+    ///
+    /// The nul terminator is not copied.
+    /// Returns an error if the data is not valid UTF-8 or if the slice is too short.
+    pub fn from_cstr(cs: &CStr, buf: &'a mut [u8]) -> Result<Self, FromCStrError> {
+        let src = str::from_utf8(cs.to_bytes())?;
+        Ok(Self::try_from_str(src, buf)?)
+    }
+
+    /// Create a new `SliceString` from a mutable slice and decode UTF-16 into it.
+    ///
+    /// Returns an error if the data is not valid UTF-16 or if the slice is too short.
+    pub fn from_utf16(buf: &'a mut [u8], src: &[u16]) -> Result<Self, FromUtf16Error> {
+        let mut needed = 0;
+        for c in char::decode_utf16(src.iter().copied()) {
+            needed += c?.len_utf8();
+        }
+        let mut s = Self::new(buf);
+        s.check_capacity(needed)?;
+        // The data has just been validated.
+        s.extend(char::decode_utf16(src.iter().copied()).map(Result::unwrap));
+        Ok(s)
+    }
+
+    /// Create a new `SliceString` from a raw pointer, a length, and a capacity.
+    ///
+    /// This is the counterpart of [`SliceString::into_raw_parts`].
+    ///
+    /// # Safety
+    /// `ptr` must be valid for reads and writes of `capacity` bytes for the lifetime `'a`
+    /// and must not be accessed through any other pointer during that time.
+    /// `len` must not exceed `capacity` and the data in `ptr[..len]` must be valid UTF-8.
+    pub unsafe fn from_raw_parts(ptr: *mut u8, len: usize, capacity: usize) -> Self {
+        Self::from_utf8_unchecked(core::slice::from_raw_parts_mut(ptr, capacity), len)
+    }
+
     /// Return a mutable reference to the inner `SliceVec`.
     ///
     /// # Safety
@@ -75,11 +379,53 @@ impl<'a> SliceString<'a> {
         unsafe { str::from_utf8_unchecked_mut(&mut self.0) }
     }
 
+    /// Return the content of the string as a byte slice.
+    pub fn as_bytes(&self) -> &[u8] {
+        self.as_str().as_bytes()
+    }
+
+    /// Return the content of the string as a mutable byte slice.
+    ///
+    /// The slice has the length of the string, not its capacity.
+    ///
+    /// # Safety
+    /// The data must be valid UTF-8 when the borrow ends.
+    pub unsafe fn as_bytes_mut(&mut self) -> &mut [u8] {
+        self.0.as_mut_slice()
+    }
+
+    /// Return a view of at most `max_bytes` bytes truncated at a character boundary.
+    ///
+    /// The string is not modified.
+    ///
+    /// ```
+    /// # use slice_string::SliceString;
+    /// let mut buf = "Grüße".as_bytes().to_owned();
+    /// let s = SliceString::try_from(&mut buf[..]).unwrap();
+    /// assert_eq!(s.as_str_limited(3), "Gr");
+    /// assert_eq!(s.as_str_limited(32), "Grüße");
+    /// ```
+    pub fn as_str_limited(&self, max_bytes: usize) -> &str {
+        &self.as_str()[..floor_char_boundary(self, max_bytes)]
+    }
+
     /// Return the maximum number of bytes this string can contain.
     pub fn capacity(&self) -> usize {
         self.0.capacity()
     }
 
+    /// Return the number of bytes that can still be appended.
+    #[inline(always)]
+    pub fn remaining_capacity(&self) -> usize {
+        self.capacity() - self.len()
+    }
+
+    /// Return whether no more bytes can be appended.
+    #[inline(always)]
+    pub fn is_full(&self) -> bool {
+        self.0.is_full()
+    }
+
     /// Set the current string length to zero.
     pub fn clear(&mut self) {
         self.0.clear()
@@ -96,6 +442,19 @@ impl<'a> SliceString<'a> {
         self.0.truncate(new_len);
     }
 
+    /// Set the length to `new_len` or return an error if it is invalid.
+    ///
+    /// This is the non-panicking variant of [`SliceString::truncate`].
+    /// Unlike `truncate`, a `new_len` exceeding the length is an error.
+    /// `new_len` must be at a character boundary.
+    pub fn try_truncate(&mut self, new_len: usize) -> Result<(), BoundaryError> {
+        if !self.is_char_boundary(new_len) {
+            return Err(BoundaryError { index: new_len });
+        }
+        self.0.truncate(new_len);
+        Ok(())
+    }
+
     /// Return the last `char` in the string, or `None` if empty.
     pub fn pop(&mut self) -> Option<char> {
         let ch = self.chars().last()?;
@@ -109,7 +468,12 @@ impl<'a> SliceString<'a> {
     /// The remaining space must be sufficient.
     pub fn push(&mut self, c: char) {
         match c.len_utf8() {
-            1 => self.0.push(c as u8),
+            1 => {
+                if let Err(err) = self.check_capacity(1) {
+                    panic!("{}", err);
+                }
+                self.0.push(c as u8)
+            }
             _ => self.push_str(c.encode_utf8(&mut [0; 4])),
         }
     }
@@ -119,341 +483,3410 @@ impl<'a> SliceString<'a> {
     /// # Panics
     /// The remaining space must be sufficient.
     pub fn push_str(&mut self, string: &str) {
+        if let Err(err) = self.check_capacity(string.len()) {
+            panic!("{}", err);
+        }
         self.0.extend_from_slice(string.as_bytes())
     }
 
-    /// Split the string and return the remainder.
+    /// Append as much of a `str` as fits into the remaining space.
     ///
-    /// The current `SliceString` capacity and length are reduced to `at`.
-    /// A new `SliceString` is returned containing the data and buffer space starting at `at`.
+    /// The string is truncated at the last character boundary that fits.
+    /// Returns the number of bytes appended.
+    pub fn push_str_lossy(&mut self, string: &str) -> usize {
+        let len = floor_char_boundary(string, self.remaining_capacity());
+        self.0.extend_from_slice(&string.as_bytes()[..len]);
+        len
+    }
+
+    /// Append as much of a `str` as fits into the remaining space.
     ///
-    /// # Panics
-    /// The split location must be at a character boundary.
-    pub fn split_off(&mut self, at: usize) -> SliceString<'a> {
-        if at <= self.len() {
-            assert!(self.is_char_boundary(at));
-        }
-        let new = self.0.split_off(at);
-        // UTF-8 validity is maintained
-        unsafe { Self::new_unchecked(new) }
+    /// Returns the appended part and the remainder that did not fit,
+    /// split at the last character boundary that fits.
+    ///
+    /// ```
+    /// # use slice_string::SliceString;
+    /// let mut buf = [0; 4];
+    /// let mut s = SliceString::new(&mut buf[..]);
+    /// let mut rest = "Hello world";
+    /// let mut chunks = 0;
+    /// while !rest.is_empty() {
+    ///     (_, rest) = s.try_push_str_partial(rest);
+    ///     // Transmit and clear `s`
+    ///     s.clear();
+    ///     chunks += 1;
+    /// }
+    /// assert_eq!(chunks, 3);
+    /// ```
+    pub fn try_push_str_partial<'s>(&mut self, string: &'s str) -> (&'s str, &'s str) {
+        let len = self.push_str_lossy(string);
+        string.split_at(len)
     }
-}
 
-impl<'a> From<SliceString<'a>> for SliceVec<'a, u8> {
-    fn from(value: SliceString<'a>) -> Self {
-        value.0
+    /// Insert a `char` at byte position `at`.
+    ///
+    /// The bytes following `at` are shifted to the right.
+    /// Returns an error if the remaining space is insufficient.
+    ///
+    /// # Panics
+    /// The location must be at a character boundary.
+    pub fn insert(&mut self, at: usize, ch: char) -> Result<(), Error> {
+        self.insert_bytes(at, ch.encode_utf8(&mut [0; 4]).as_bytes())
     }
-}
 
-impl<'a> From<SliceString<'a>> for (&'a mut [u8], usize) {
-    fn from(mut value: SliceString<'a>) -> Self {
-        let data = value.as_mut_ptr();
-        let len = value.capacity();
-        // Unfortunately there is no way to destructure SliceVec
-        let data = unsafe { core::slice::from_raw_parts_mut(data, len) };
-        (data, value.len())
+    /// Insert a `str` at byte position `at`.
+    ///
+    /// The bytes following `at` are shifted to the right which is `O(n)`
+    /// in the length of the tail.
+    /// Returns an error if the remaining space is insufficient.
+    ///
+    /// # Panics
+    /// The location must be at a character boundary.
+    pub fn insert_str(&mut self, at: usize, string: &str) -> Result<(), Error> {
+        self.insert_bytes(at, string.as_bytes())
     }
-}
 
-impl<'a> TryFrom<&'a mut [u8]> for SliceString<'a> {
-    type Error = str::Utf8Error;
+    /// Remove and return the `char` at byte position `at`.
+    ///
+    /// The bytes following the `char` are shifted to the left.
+    ///
+    /// # Panics
+    /// The location must be at a character boundary and less than the length.
+    pub fn remove(&mut self, at: usize) -> char {
+        let ch = self[at..]
+            .chars()
+            .next()
+            .expect("cannot remove a char from the end of a string");
+        self.remove_bytes(at, at + ch.len_utf8());
+        ch
+    }
 
-    fn try_from(value: &'a mut [u8]) -> Result<Self, Self::Error> {
-        Self::from_utf8(value, value.len())
+    /// Retain only the `char`s for which the predicate returns `true`.
+    ///
+    /// The retained `char`s are kept in order and moved to the front in place.
+    pub fn retain<F: FnMut(char) -> bool>(&mut self, mut f: F) {
+        let len = self.len();
+        // Keep the string empty and valid should the predicate panic.
+        self.0.set_len(0);
+        let buf = self.buf_mut();
+        let mut read = 0;
+        let mut write = 0;
+        while read < len {
+            // The data in `buf[read..len]` has not been touched and is valid UTF-8.
+            let ch = unsafe { str::from_utf8_unchecked(&buf[read..len]) }
+                .chars()
+                .next()
+                .unwrap();
+            let n = ch.len_utf8();
+            if f(ch) {
+                buf.copy_within(read..read + n, write);
+                write += n;
+            }
+            read += n;
+        }
+        // `buf[..write]` contains only complete chars.
+        self.0.set_len(write);
     }
-}
 
-impl<'a> TryFrom<SliceVec<'a, u8>> for SliceString<'a> {
-    type Error = str::Utf8Error;
+    /// Collapse runs of consecutive `ch` into a single `ch` in place.
+    ///
+    /// ```
+    /// # use slice_string::SliceString;
+    /// let mut buf = *b"a  b   c";
+    /// let mut s = SliceString::try_from(&mut buf[..]).unwrap();
+    /// s.deduplicate_char(' ');
+    /// assert_eq!(s, "a b c");
+    /// ```
+    pub fn deduplicate_char(&mut self, ch: char) {
+        let mut prev = None;
+        self.retain(|c| {
+            let dup = c == ch && prev == Some(ch);
+            prev = Some(c);
+            !dup
+        });
+    }
 
-    fn try_from(value: SliceVec<'a, u8>) -> Result<Self, Self::Error> {
-        str::from_utf8(&value)?;
-        // UTF-8 validity has just been checked.
-        Ok(unsafe { Self::new_unchecked(value) })
+    /// Replace runs of whitespace with a single ASCII space and trim both ends in place.
+    ///
+    /// Whitespace is defined by [`char::is_whitespace`].
+    ///
+    /// ```
+    /// # use slice_string::SliceString;
+    /// let mut buf = *b" set\t gain\r\n 3 ";
+    /// let mut s = SliceString::try_from(&mut buf[..]).unwrap();
+    /// s.normalize_whitespace();
+    /// assert_eq!(s, "set gain 3");
+    /// ```
+    pub fn normalize_whitespace(&mut self) {
+        self.trim_in_place();
+        let len = self.len();
+        let buf = self.buf_mut();
+        let mut read = 0;
+        let mut write = 0;
+        let mut space = false;
+        while read < len {
+            // The data in `buf[read..len]` has not been touched and is valid UTF-8.
+            let ch = unsafe { str::from_utf8_unchecked(&buf[read..len]) }
+                .chars()
+                .next()
+                .unwrap();
+            let n = ch.len_utf8();
+            if !ch.is_whitespace() {
+                buf.copy_within(read..read + n, write);
+                write += n;
+                space = false;
+            } else if !space {
+                // A single byte never overtakes `read`.
+                buf[write] = b' ';
+                write += 1;
+                space = true;
+            }
+            read += n;
+        }
+        // `buf[..write]` contains only complete chars.
+        self.0.set_len(write);
     }
-}
 
-impl<'a> ops::Deref for SliceString<'a> {
-    type Target = str;
+    /// Remove the given byte range and return an iterator over the removed `char`s.
+    ///
+    /// The range is removed when the iterator is dropped,
+    /// even if it has not been consumed entirely.
+    ///
+    /// # Panics
+    /// The range bounds must be at character boundaries and within the string.
+    pub fn drain<R: ops::RangeBounds<usize>>(&mut self, range: R) -> Drain<'_, 'a> {
+        let (start, end) = self.range_bounds(range);
+        Drain::new(self, start, end)
+    }
 
-    fn deref(&self) -> &str {
-        self.as_str()
+    /// Replace the given byte range with a `str`.
+    ///
+    /// The tail of the string is shifted to accommodate the replacement.
+    /// Returns an error and leaves the string unchanged if the remaining space is insufficient.
+    ///
+    /// # Panics
+    /// The range bounds must be at character boundaries and within the string.
+    pub fn replace_range<R: ops::RangeBounds<usize>>(
+        &mut self,
+        range: R,
+        replace_with: &str,
+    ) -> Result<(), Error> {
+        let (start, end) = self.range_bounds(range);
+        self.splice(start, end, replace_with.as_bytes())
     }
-}
 
-impl<'a> ops::DerefMut for SliceString<'a> {
-    fn deref_mut(&mut self) -> &mut str {
-        self.as_mut_str()
+    /// Split the string and return the remainder.
+    ///
+    /// The current `SliceString` capacity and length are reduced to `at`.
+    /// A new `SliceString` is returned containing the data and buffer space starting at `at`.
+    ///
+    /// # Panics
+    /// The split location must be at most `len()` and at a character boundary.
+    pub fn split_off(&mut self, at: usize) -> SliceString<'a> {
+        assert!(self.is_char_boundary(at));
+        let new = self.0.split_off(at);
+        // UTF-8 validity is maintained
+        unsafe { Self::new_unchecked(new) }
     }
-}
 
-impl<'a> AsRef<str> for SliceString<'a> {
-    fn as_ref(&self) -> &str {
-        self
+    /// Split the string and return the remainder, or an error if `at` is invalid.
+    ///
+    /// This is the non-panicking variant of [`SliceString::split_off`].
+    /// `at` must be at a character boundary within the string.
+    ///
+    /// ```
+    /// # use slice_string::{BoundaryError, SliceString};
+    /// let mut buf = "aä".as_bytes().to_owned();
+    /// let mut s = SliceString::try_from(&mut buf[..]).unwrap();
+    /// assert_eq!(s.try_split_off(2), Err(BoundaryError { index: 2 }));
+    /// assert_eq!(s.try_split_off(1).unwrap(), "ä");
+    /// ```
+    pub fn try_split_off(&mut self, at: usize) -> Result<SliceString<'a>, BoundaryError> {
+        if !self.is_char_boundary(at) {
+            return Err(BoundaryError { index: at });
+        }
+        Ok(self.split_off(at))
     }
-}
 
-impl<'a> AsMut<str> for SliceString<'a> {
-    fn as_mut(&mut self) -> &mut str {
-        self
+    /// Append `n` copies of a `char` to the string.
+    ///
+    /// Returns the number of `char`s appended or an error if the remaining space
+    /// is insufficient for all copies. Nothing is appended on error.
+    pub fn push_char_n(&mut self, c: char, n: usize) -> Result<usize, Error> {
+        let mut enc = [0; 4];
+        let enc = c.encode_utf8(&mut enc).as_bytes();
+        self.check_capacity(enc.len().saturating_mul(n))?;
+        let len = self.len();
+        let new_len = len + enc.len() * n;
+        let buf = &mut self.buf_mut()[len..new_len];
+        if let [b] = enc {
+            buf.fill(*b);
+        } else {
+            for chunk in buf.chunks_exact_mut(enc.len()) {
+                chunk.copy_from_slice(enc);
+            }
+        }
+        self.0.set_len(new_len);
+        Ok(n)
     }
-}
 
-impl<'a> AsRef<SliceVec<'a, u8>> for SliceString<'a> {
-    fn as_ref(&self) -> &SliceVec<'a, u8> {
-        &self.0
+    /// Truncate the string to at most `n` `char`s.
+    ///
+    /// This is `O(n)` and does nothing if the string has `n` or fewer chars.
+    ///
+    /// ```
+    /// # use slice_string::SliceString;
+    /// let mut buf = "Grüße".as_bytes().to_owned();
+    /// let mut s = SliceString::try_from(&mut buf[..]).unwrap();
+    /// s.truncate_chars(3);
+    /// assert_eq!(s, "Grü");
+    /// ```
+    pub fn truncate_chars(&mut self, n: usize) {
+        if let Some(len) = self.char_offset(n) {
+            self.0.truncate(len);
+        }
     }
-}
 
-impl<'a> AsRef<[u8]> for SliceString<'a> {
-    fn as_ref(&self) -> &[u8] {
-        self.0.as_slice()
+    /// Set the length to be at most the given number of `u8`, rounding down to a character boundary.
+    ///
+    /// Unlike [`SliceString::truncate`] this never panics.
+    /// Returns the new length.
+    pub fn truncate_to_char_boundary(&mut self, new_len: usize) -> usize {
+        let new_len = floor_char_boundary(self, new_len);
+        self.0.truncate(new_len);
+        new_len
     }
-}
 
-impl<'a> fmt::Write for SliceString<'a> {
-    fn write_str(&mut self, s: &str) -> Result<(), fmt::Error> {
-        if self.capacity() < self.len() + s.len() {
-            return Err(fmt::Error);
+    /// Append the `char`s of an iterator.
+    ///
+    /// Returns an error at the first `char` that does not fit.
+    /// The `char`s preceding it remain appended.
+    pub fn try_extend_chars<I: IntoIterator<Item = char>>(&mut self, iter: I) -> Result<(), Error> {
+        for c in iter {
+            self.check_capacity(c.len_utf8())?;
+            self.push(c);
         }
-        self.push_str(s);
         Ok(())
     }
 
-    fn write_char(&mut self, c: char) -> Result<(), fmt::Error> {
-        if self.capacity() < self.len() + c.len_utf8() {
-            return Err(fmt::Error);
-        }
-        self.push(c);
+    /// Replace the content of the string with a copy of a `str`.
+    ///
+    /// The string is cleared before the copy is attempted:
+    /// if the capacity is insufficient an error is returned and the string is left empty.
+    pub fn copy_from_str(&mut self, string: &str) -> Result<(), Error> {
+        self.clear();
+        self.check_capacity(string.len())?;
+        self.push_str(string);
         Ok(())
     }
-}
 
-impl<'a> fmt::Debug for SliceString<'a> {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        <str as fmt::Debug>::fmt(self, f)
+    /// Split the string and return the front.
+    ///
+    /// A new `SliceString` is returned containing the data and buffer space before `at`.
+    /// The current `SliceString` retains the data and buffer space starting at `at`.
+    ///
+    /// # Panics
+    /// The split location must be at most `len()` and at a character boundary.
+    pub fn split_off_front(&mut self, at: usize) -> SliceString<'a> {
+        assert!(self.is_char_boundary(at));
+        let mut tail = self.split_off(at);
+        core::mem::swap(self, &mut tail);
+        tail
     }
-}
 
-impl<'a> fmt::Display for SliceString<'a> {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        <str as fmt::Display>::fmt(self, f)
+    /// Return a subslice of the string or `None` if the index is out of bounds
+    /// or not at character boundaries.
+    pub fn get<I: slice::SliceIndex<str>>(&self, i: I) -> Option<&I::Output> {
+        self.as_str().get(i)
+    }
+
+    /// Return a mutable subslice of the string or `None` if the index is out of bounds
+    /// or not at character boundaries.
+    pub fn get_mut<I: slice::SliceIndex<str>>(&mut self, i: I) -> Option<&mut I::Output> {
+        self.as_mut_str().get_mut(i)
+    }
+
+    /// Remove a prefix from the string, shifting the remainder to the left.
+    ///
+    /// Returns whether the string started with `prefix`.
+    pub fn strip_prefix_in_place(&mut self, prefix: &str) -> bool {
+        let found = self.starts_with(prefix);
+        if found {
+            self.remove_bytes(0, prefix.len());
+        }
+        found
+    }
+
+    /// Remove a suffix from the string.
+    ///
+    /// Returns whether the string ended with `suffix`.
+    pub fn strip_suffix_in_place(&mut self, suffix: &str) -> bool {
+        let found = self.ends_with(suffix);
+        if found {
+            self.0.truncate(self.len() - suffix.len());
+        }
+        found
+    }
+
+    /// Convert the string to its ASCII upper case equivalent in place.
+    ///
+    /// Non-ASCII characters are unchanged.
+    pub fn make_ascii_uppercase(&mut self) {
+        self.as_mut_str().make_ascii_uppercase()
+    }
+
+    /// Convert the string to its ASCII lower case equivalent in place.
+    ///
+    /// Non-ASCII characters are unchanged.
+    pub fn make_ascii_lowercase(&mut self) {
+        self.as_mut_str().make_ascii_lowercase()
+    }
+
+    /// Return whether the string equals `other` ignoring ASCII case.
+    ///
+    /// ```
+    /// # use slice_string::SliceString;
+    /// let mut buf = *b"Content-Length";
+    /// let header = SliceString::try_from(&mut buf[..]).unwrap();
+    /// assert!(header.eq_ignore_ascii_case("content-length"));
+    /// ```
+    pub fn eq_ignore_ascii_case(&self, other: &str) -> bool {
+        self.as_str().eq_ignore_ascii_case(other)
+    }
+
+    /// Return whether the string and `other` consist of the same sequence of `char`s.
+    ///
+    /// UTF-8 encodes every `char` in exactly one way, so for two `str`s this
+    /// always agrees with `==`. A byte order mark is the `char` `U+FEFF` and
+    /// is compared like any other `char`.
+    pub fn chars_equal(&self, other: &str) -> bool {
+        self.chars().eq(other.chars())
+    }
+
+    /// Return whether all bytes of the string are ASCII.
+    pub fn is_ascii(&self) -> bool {
+        self.as_str().is_ascii()
+    }
+
+    /// Return whether every `char` of the string is in the ASCII range.
+    ///
+    /// Since the string is valid UTF-8 this is equivalent to [`SliceString::is_ascii`]:
+    /// multi-byte sequences consist only of non-ASCII bytes.
+    pub fn chars_are_ascii(&self) -> bool {
+        self.is_ascii()
+    }
+
+    /// Split the string at the first line terminator, `\n` or `\r\n`.
+    ///
+    /// Returns the line without the terminator and the remainder after it
+    /// or `None` if there is no `\n`.
+    ///
+    /// ```
+    /// # use slice_string::SliceString;
+    /// let mut buf = *b"+CSQ: 20,99\r\nOK";
+    /// let rx = SliceString::try_from(&mut buf[..]).unwrap();
+    /// assert_eq!(rx.split_at_newline(), Some(("+CSQ: 20,99", "OK")));
+    /// ```
+    pub fn split_at_newline(&self) -> Option<(&str, &str)> {
+        let (head, tail) = self.as_str().split_once('\n')?;
+        Some((head.strip_suffix('\r').unwrap_or(head), tail))
+    }
+
+    /// Return the number of `char`s in the string.
+    ///
+    /// Unlike [`str::len`] which returns the number of bytes in `O(1)`,
+    /// this iterates over the entire string and is `O(n)`.
+    /// Consider caching the result.
+    pub fn chars_count(&self) -> usize {
+        self.chars().count()
+    }
+
+    /// Return the number of complete `char`s within the first `bytes` bytes.
+    ///
+    /// A `char` straddling `bytes` is not counted and `bytes` beyond the length
+    /// count the entire string. This is `O(bytes)`.
+    ///
+    /// ```
+    /// # use slice_string::SliceString;
+    /// let mut buf = "Grüße".as_bytes().to_owned();
+    /// let s = SliceString::try_from(&mut buf[..]).unwrap();
+    /// assert_eq!(s.char_len_for_bytes(3), 2);
+    /// assert_eq!(s.char_len_for_bytes(4), 3);
+    /// ```
+    pub fn char_len_for_bytes(&self, bytes: usize) -> usize {
+        self.as_str_limited(bytes).chars().count()
+    }
+
+    /// Return the number of occurrences of `ch`.
+    ///
+    /// ```
+    /// # use slice_string::SliceString;
+    /// let mut buf = *b"1.5,2,,-3";
+    /// let row = SliceString::try_from(&mut buf[..]).unwrap();
+    /// assert_eq!(row.count_char(','), 3);
+    /// ```
+    pub fn count_char(&self, ch: char) -> usize {
+        self.matches(ch).count()
+    }
+
+    /// Return the number of non-overlapping occurrences of `pattern`.
+    ///
+    /// An empty `pattern` matches at every `char` boundary.
+    ///
+    /// ```
+    /// # use slice_string::SliceString;
+    /// let mut buf = *b"OK\r\nOK\r\nERROR\r\n";
+    /// let rx = SliceString::try_from(&mut buf[..]).unwrap();
+    /// assert_eq!(rx.count_occurrences("OK\r\n"), 2);
+    /// ```
+    pub fn count_occurrences(&self, pattern: &str) -> usize {
+        self.matches(pattern).count()
+    }
+
+    /// Return the `n`th `char` or `None` if there are not more than `n` chars.
+    ///
+    /// This is `O(n)`.
+    pub fn char_at(&self, n: usize) -> Option<char> {
+        self.chars().nth(n)
+    }
+
+    /// Return the byte index of the `n`th `char` or `None` if there are not more than `n` chars.
+    ///
+    /// This is `O(n)`.
+    pub fn byte_index_of_char(&self, n: usize) -> Option<usize> {
+        self.char_indices().nth(n).map(|(i, _)| i)
+    }
+
+    /// Split the string into two at the `n`th `char`.
+    ///
+    /// This is `O(n)`.
+    ///
+    /// # Panics
+    /// The string must contain at least `n` chars.
+    pub fn split_at_char(&self, n: usize) -> (&str, &str) {
+        let at = self.char_offset(n).expect("char index out of bounds");
+        self.split_at(at)
+    }
+
+    /// Remove and return the first `char` in the string, or `None` if empty.
+    ///
+    /// The remaining bytes are shifted to the left which is `O(n)`.
+    pub fn pop_front(&mut self) -> Option<char> {
+        let ch = self.chars().next()?;
+        self.remove_bytes(0, ch.len_utf8());
+        Some(ch)
+    }
+
+    /// Remove leading and trailing whitespace in place.
+    pub fn trim_in_place(&mut self) {
+        self.trim_end_in_place();
+        self.trim_start_in_place();
+    }
+
+    /// Remove leading whitespace in place.
+    ///
+    /// The remaining bytes are shifted to the left.
+    pub fn trim_start_in_place(&mut self) {
+        let start = self.len() - self.trim_start().len();
+        self.remove_bytes(0, start);
+    }
+
+    /// Remove trailing whitespace in place.
+    pub fn trim_end_in_place(&mut self) {
+        self.0.truncate(self.trim_end().len());
+    }
+
+    /// Remove all leading and trailing `char`s matching a pattern in place.
+    pub fn trim_matches_in_place<P: CharPattern>(&mut self, mut pat: P) {
+        self.trim_end_matches_in_place(|c| pat.matches(c));
+        self.trim_start_matches_in_place(|c| pat.matches(c));
+    }
+
+    /// Remove all leading `char`s matching a pattern in place.
+    ///
+    /// The remaining bytes are shifted to the left.
+    pub fn trim_start_matches_in_place<P: CharPattern>(&mut self, mut pat: P) {
+        let start = self.len() - self.trim_start_matches(|c| pat.matches(c)).len();
+        self.remove_bytes(0, start);
+    }
+
+    /// Remove all trailing `char`s matching a pattern in place.
+    pub fn trim_end_matches_in_place<P: CharPattern>(&mut self, mut pat: P) {
+        self.0
+            .truncate(self.trim_end_matches(|c| pat.matches(c)).len());
+    }
+
+    /// Reverse the order of the `char`s in place.
+    pub fn reverse_in_place(&mut self) {
+        let bytes = self.0.as_mut_slice();
+        bytes.reverse();
+        // Each encoded char is now reversed and terminated by its leading byte.
+        let mut start = 0;
+        for i in 0..bytes.len() {
+            if bytes[i] & 0xc0 != 0x80 {
+                bytes[start..=i].reverse();
+                start = i + 1;
+            }
+        }
+    }
+
+    /// Append `fill` until the string is at least `total_len` chars long.
+    ///
+    /// Returns an error if the remaining space is insufficient. Nothing is appended on error.
+    ///
+    /// ```
+    /// # use slice_string::SliceString;
+    /// let mut buf = [0; 8];
+    /// let mut s = SliceString::new(&mut buf[..]);
+    /// s.push_str("V1");
+    /// s.pad_right(5, '.').unwrap();
+    /// assert_eq!(s, "V1...");
+    /// ```
+    pub fn pad_right(&mut self, total_len: usize, fill: char) -> Result<(), Error> {
+        let n = total_len.saturating_sub(self.chars_count());
+        self.push_char_n(fill, n)?;
+        Ok(())
+    }
+
+    /// Prepend `fill` until the string is at least `total_len` chars long.
+    ///
+    /// The content is shifted to the right which is `O(n)`.
+    /// Returns an error if the remaining space is insufficient. Nothing is changed on error.
+    ///
+    /// ```
+    /// # use slice_string::SliceString;
+    /// let mut buf = [0; 8];
+    /// let mut s = SliceString::new(&mut buf[..]);
+    /// s.push_str("42");
+    /// s.pad_left(5, ' ').unwrap();
+    /// assert_eq!(s, "   42");
+    /// ```
+    pub fn pad_left(&mut self, total_len: usize, fill: char) -> Result<(), Error> {
+        let n = total_len.saturating_sub(self.chars_count());
+        let len = self.len();
+        self.push_char_n(fill, n)?;
+        let pad = self.len() - len;
+        // Both the content and the padding are valid UTF-8
+        self.0.rotate_right(pad);
+        Ok(())
+    }
+
+    /// Center the string in a field of `total_len` chars using `fill`.
+    ///
+    /// `floor(pad / 2)` fill chars are prepended and `ceil(pad / 2)` are appended.
+    /// Returns an error if the remaining space is insufficient. Nothing is changed on error.
+    ///
+    /// ```
+    /// # use slice_string::SliceString;
+    /// let mut buf = [0; 8];
+    /// let mut s = SliceString::new(&mut buf[..]);
+    /// s.push_str("OK");
+    /// s.center(7, '*').unwrap();
+    /// assert_eq!(s, "**OK***");
+    /// ```
+    pub fn center(&mut self, total_len: usize, fill: char) -> Result<(), Error> {
+        let count = self.chars_count();
+        let pad = total_len.saturating_sub(count);
+        self.check_capacity(fill.len_utf8().saturating_mul(pad))?;
+        self.pad_left(count + pad / 2, fill)?;
+        self.pad_right(total_len, fill)
+    }
+
+    /// Append as many complete copies of a `str` as fit into the remaining space.
+    ///
+    /// Returns the number of copies appended. Nothing is appended for an empty `str`.
+    pub fn repeat_fill(&mut self, string: &str) -> usize {
+        if string.is_empty() {
+            return 0;
+        }
+        let n = self.remaining_capacity() / string.len();
+        for _ in 0..n {
+            self.push_str(string);
+        }
+        n
+    }
+
+    /// Append formatted text, truncating it if the remaining space is insufficient.
+    ///
+    /// The output is truncated at a character boundary and formatting stops at that point.
+    /// Returns the number of bytes appended.
+    pub fn write_fmt_lossy(&mut self, args: fmt::Arguments<'_>) -> usize {
+        let len = self.len();
+        // Truncation is the only error source and is not reported.
+        fmt::write(&mut LossyWriter(self), args).ok();
+        self.len() - len
+    }
+
+    /// Append the representation of a signed integer in the given radix.
+    ///
+    /// Digits above 9 are lower case letters. Negative numbers are prefixed with `-`.
+    /// Returns an error and appends nothing if the remaining space is insufficient.
+    ///
+    /// # Panics
+    /// The radix must be in `2..=36`.
+    pub fn push_int(&mut self, n: i64, radix: u8) -> Result<(), Error> {
+        self.push_digits(n < 0, n.unsigned_abs(), radix, 0, false)
+    }
+
+    /// Append the representation of an unsigned integer in the given radix.
+    ///
+    /// Digits above 9 are lower case letters.
+    /// Returns an error and appends nothing if the remaining space is insufficient.
+    ///
+    /// # Panics
+    /// The radix must be in `2..=36`.
+    pub fn push_uint(&mut self, n: u64, radix: u8) -> Result<(), Error> {
+        self.push_digits(false, n, radix, 0, false)
+    }
+
+    /// Append the decimal representation of a float with a fixed number of decimal places.
+    ///
+    /// The value is rounded to the given number of decimal places.
+    /// Non-finite values are represented as `NaN`, `inf`, and `-inf`.
+    /// Returns an error and appends nothing if the remaining space is insufficient.
+    pub fn push_float(&mut self, v: f64, decimal_places: u8) -> Result<(), Error> {
+        self.push_fmt(format_args!("{:.*}", decimal_places as usize, v))
+    }
+
+    /// Append the hexadecimal representation of an integer.
+    ///
+    /// The digits are left-padded with `0` to at least `min_digits`.
+    /// Returns an error and appends nothing if the remaining space is insufficient.
+    pub fn push_hex(&mut self, n: u64, uppercase: bool, min_digits: usize) -> Result<(), Error> {
+        self.push_digits(false, n, 16, min_digits, uppercase)
+    }
+
+    /// Append the binary representation of an integer.
+    ///
+    /// The digits are left-padded with `0` to at least `min_digits`.
+    /// Returns an error and appends nothing if the remaining space is insufficient.
+    pub fn push_binary(&mut self, n: u64, min_digits: usize) -> Result<(), Error> {
+        self.push_digits(false, n, 2, min_digits, false)
+    }
+
+    /// Copy the content into a newly allocated `String`.
+    #[cfg(feature = "alloc")]
+    pub fn into_string(self) -> String {
+        String::from(self.as_str())
+    }
+
+    /// Create a new `SliceString` backed by `buf` containing a copy of this string.
+    ///
+    /// Returns an error if `buf` is too short.
+    pub fn clone_into_buf<'b>(&self, buf: &'b mut [u8]) -> Result<SliceString<'b>, Error> {
+        SliceString::try_from_str(self, buf)
+    }
+
+    /// Replace the content of `target` with a copy of this string.
+    ///
+    /// See [`SliceString::copy_from_str`] for the behavior on error.
+    pub fn clone_into_slice_string(&self, target: &mut SliceString<'_>) -> Result<(), Error> {
+        target.copy_from_str(self)
+    }
+
+    /// Exchange the content of this string with the content of `other`.
+    ///
+    /// The buffers are not exchanged, only their content, so the capacities may differ.
+    /// Returns an error and leaves both strings unchanged if either content
+    /// does not fit into the other buffer.
+    pub fn swap_content(&mut self, other: &mut SliceString<'_>) -> Result<(), Error> {
+        let (len, other_len) = (self.len(), other.len());
+        for (needed, available) in [(other_len, self.capacity()), (len, other.capacity())] {
+            if needed > available {
+                return Err(Error { needed, available });
+            }
+        }
+        let common = len.min(other_len);
+        let (buf, other_buf) = (self.buf_mut(), other.buf_mut());
+        buf[..common].swap_with_slice(&mut other_buf[..common]);
+        if len > other_len {
+            other_buf[common..len].copy_from_slice(&buf[common..len]);
+        } else {
+            buf[common..other_len].copy_from_slice(&other_buf[common..other_len]);
+        }
+        self.0.set_len(other_len);
+        other.0.set_len(len);
+        Ok(())
+    }
+
+    /// Insert a `str` at the start of the string.
+    ///
+    /// This is equivalent to `insert_str(0, string)` and shifts the entire content
+    /// to the right which is `O(n)`.
+    /// Returns an error if the remaining space is insufficient.
+    pub fn prepend(&mut self, string: &str) -> Result<(), Error> {
+        self.insert_str(0, string)
+    }
+
+    /// Replace the content of the string with a copy of `src`.
+    ///
+    /// This is the same as [`SliceString::copy_from_str`]: the string is cleared
+    /// before the copy is attempted and is guaranteed to be empty if an error is returned.
+    pub fn overwrite_from(&mut self, src: &str) -> Result<(), Error> {
+        self.copy_from_str(src)
+    }
+
+    /// Return the byte index of the first occurrence of a `char` or `None` if not found.
+    pub fn find_char(&self, ch: char) -> Option<usize> {
+        self.as_str().find(ch)
+    }
+
+    /// Return the byte index of the last occurrence of a `char` or `None` if not found.
+    pub fn rfind_char(&self, ch: char) -> Option<usize> {
+        self.as_str().rfind(ch)
+    }
+
+    /// Return whether the string starts with a `char`.
+    ///
+    /// ```
+    /// # use slice_string::SliceString;
+    /// let mut buf = *b"+CSQ: 20,99";
+    /// let response = SliceString::try_from(&mut buf[..]).unwrap();
+    /// assert!(response.starts_with_char('+'));
+    /// ```
+    pub fn starts_with_char(&self, ch: char) -> bool {
+        self.as_str().starts_with(ch)
+    }
+
+    /// Return whether the string ends with a `char`.
+    ///
+    /// ```
+    /// # use slice_string::SliceString;
+    /// let mut buf = *b"OK\n";
+    /// let line = SliceString::try_from(&mut buf[..]).unwrap();
+    /// assert!(line.ends_with_char('\n'));
+    /// ```
+    pub fn ends_with_char(&self, ch: char) -> bool {
+        self.as_str().ends_with(ch)
+    }
+
+    /// Return the largest character boundary at or before byte `index`.
+    ///
+    /// Indices beyond the end of the string return the length.
+    pub fn char_boundary_before(&self, index: usize) -> usize {
+        floor_char_boundary(self, index)
+    }
+
+    /// Return the smallest character boundary at or after byte `index`.
+    ///
+    /// Indices beyond the end of the string return the length.
+    pub fn char_boundary_after(&self, index: usize) -> usize {
+        if index >= self.len() {
+            self.len()
+        } else {
+            // A char boundary is at most three bytes ahead and the length is always a boundary.
+            (index..=self.len())
+                .find(|&i| self.is_char_boundary(i))
+                .unwrap()
+        }
+    }
+
+    /// Return an iterator over all character boundaries in ascending order.
+    ///
+    /// This includes `0` and the length of the string.
+    pub fn char_boundaries(&self) -> impl DoubleEndedIterator<Item = usize> + '_ {
+        self.char_indices()
+            .map(|(i, _)| i)
+            .chain(core::iter::once(self.len()))
+    }
+
+    /// Return an iterator over chunks of at most `chunk_size` bytes split at character boundaries.
+    ///
+    /// Chunks are shorter than `chunk_size` where a `char` would otherwise be split.
+    /// A single `char` longer than `chunk_size` is returned as a chunk of its own.
+    ///
+    /// # Panics
+    /// `chunk_size` must not be zero.
+    ///
+    /// ```
+    /// # use slice_string::SliceString;
+    /// let mut buf = "Grüße".as_bytes().to_owned();
+    /// let s = SliceString::try_from(&mut buf[..]).unwrap();
+    /// assert!(s.iter_chunks(4).eq(["Grü", "ße"]));
+    /// ```
+    pub fn iter_chunks(&self, chunk_size: usize) -> impl Iterator<Item = &str> + '_ {
+        assert!(chunk_size != 0, "chunk size must be non-zero");
+        let mut rest = self.as_str();
+        core::iter::from_fn(move || {
+            let ch = rest.chars().next()?;
+            let len = floor_char_boundary(rest, chunk_size).max(ch.len_utf8());
+            let (chunk, tail) = rest.split_at(len);
+            rest = tail;
+            Some(chunk)
+        })
+    }
+
+    /// Decompose the string into a raw pointer to the buffer, the length, and the capacity.
+    ///
+    /// The lifetime of the buffer is not tracked anymore.
+    /// Use [`SliceString::from_raw_parts`] to reconstruct the string.
+    pub fn into_raw_parts(mut self) -> (*mut u8, usize, usize) {
+        (self.0.as_mut_ptr(), self.len(), self.capacity())
+    }
+
+    /// Append a nul character (`'\0'`) to the string.
+    ///
+    /// Returns an error if the string is full.
+    pub fn push_nul(&mut self) -> Result<(), Error> {
+        self.check_capacity(1)?;
+        self.push('\0');
+        Ok(())
+    }
+
+    /// Return the string as a `CStr` if it ends with its only nul character.
+    ///
+    /// Returns `None` if the string does not end with a nul character
+    /// or if it contains interior nul characters.
+    pub fn as_cstr_after_nul(&self) -> Option<&CStr> {
+        CStr::from_bytes_with_nul(self.as_bytes()).ok()
+    }
+
+    /// Encode the string as UTF-16 into `buf`.
+    ///
+    /// Returns the number of `u16` code units written or an error
+    /// if `buf` is too short, in which case nothing is written.
+    /// The error counts `u16` code units rather than bytes.
+    pub fn encode_utf16_into(&self, buf: &mut [u16]) -> Result<usize, Error> {
+        let needed = self.encode_utf16().count();
+        if needed > buf.len() {
+            return Err(Error {
+                needed,
+                available: buf.len(),
+            });
+        }
+        for (dst, src) in buf.iter_mut().zip(self.encode_utf16()) {
+            *dst = src;
+        }
+        Ok(needed)
+    }
+
+    /// Encode the string as Latin-1 (ISO 8859-1) into `dst`.
+    ///
+    /// `char`s up to `U+00FF` map to the byte of the same value and all others
+    /// are replaced by `?`. Encoding stops when `dst` is full.
+    /// Returns the number of bytes written.
+    ///
+    /// ```
+    /// # use slice_string::SliceString;
+    /// let mut buf = "25°C ≈ 77°F".as_bytes().to_owned();
+    /// let s = SliceString::try_from(&mut buf[..]).unwrap();
+    /// let mut lcd = [0; 16];
+    /// let n = s.encode_latin1_lossy_into(&mut lcd);
+    /// assert_eq!(&lcd[..n], b"25\xb0C ? 77\xb0F");
+    /// ```
+    pub fn encode_latin1_lossy_into(&self, dst: &mut [u8]) -> usize {
+        let mut n = 0;
+        for (d, ch) in dst.iter_mut().zip(self.chars()) {
+            *d = u8::try_from(ch).unwrap_or(b'?');
+            n += 1;
+        }
+        n
+    }
+
+    /// Replace the content of `dst` with the ASCII upper case equivalent of this string.
+    ///
+    /// Non-ASCII characters are copied unchanged.
+    /// Returns an error and leaves `dst` empty if its capacity is insufficient.
+    pub fn to_ascii_uppercase_into(&self, dst: &mut SliceString<'_>) -> Result<(), Error> {
+        dst.copy_from_str(self)?;
+        dst.make_ascii_uppercase();
+        Ok(())
+    }
+
+    /// Replace the content of `dst` with the ASCII lower case equivalent of this string.
+    ///
+    /// Non-ASCII characters are copied unchanged.
+    /// Returns an error and leaves `dst` empty if its capacity is insufficient.
+    pub fn to_ascii_lowercase_into(&self, dst: &mut SliceString<'_>) -> Result<(), Error> {
+        dst.copy_from_str(self)?;
+        dst.make_ascii_lowercase();
+        Ok(())
+    }
+
+    /// Replace the content of `dst` with the `char`s of this string mapped by `f`.
+    ///
+    /// Returns an error at the first `char` that does not fit.
+    /// The `char`s preceding it remain in `dst`.
+    pub fn map_chars_into<F: FnMut(char) -> char>(
+        &self,
+        dst: &mut SliceString<'_>,
+        f: F,
+    ) -> Result<(), Error> {
+        dst.clear();
+        dst.try_extend_chars(self.chars().map(f))
+    }
+
+    /// Replace the content of `dst` with the `char`s of this string for which `f` returns `true`.
+    ///
+    /// Returns an error at the first retained `char` that does not fit.
+    /// The `char`s preceding it remain in `dst`.
+    pub fn filter_chars_into<F: FnMut(char) -> bool>(
+        &self,
+        dst: &mut SliceString<'_>,
+        mut f: F,
+    ) -> Result<(), Error> {
+        dst.clear();
+        dst.try_extend_chars(self.chars().filter(|&c| f(c)))
+    }
+
+    /// Replace all occurrences of the `char` `from` with `to` in place.
+    ///
+    /// If the encodings differ in length the content is shifted accordingly.
+    /// Returns an error and leaves the string unchanged if the remaining space is insufficient.
+    pub fn char_replace_all(&mut self, from: char, to: char) -> Result<(), Error> {
+        let count = self.matches(from).count();
+        let (mut f, mut t) = ([0; 4], [0; 4]);
+        let from = from.encode_utf8(&mut f).as_bytes();
+        let to = to.encode_utf8(&mut t).as_bytes();
+        let len = self.len();
+        self.check_capacity(to.len().saturating_sub(from.len()) * count)?;
+        let new_len = len + count * to.len() - count * from.len();
+        let buf = self.buf_mut();
+        // A complete UTF-8 encoded char can only match at a char boundary.
+        if to.len() <= from.len() {
+            // Shrinking or equal: move forward, the write position trails the read position.
+            let (mut read, mut write) = (0, 0);
+            while read < len {
+                if buf[read..len].starts_with(from) {
+                    buf[write..write + to.len()].copy_from_slice(to);
+                    read += from.len();
+                    write += to.len();
+                } else {
+                    buf[write] = buf[read];
+                    read += 1;
+                    write += 1;
+                }
+            }
+        } else {
+            // Growing: move backward, the write position leads the read position.
+            let (mut read, mut write) = (len, new_len);
+            while read > 0 {
+                if buf[..read].ends_with(from) {
+                    write -= to.len();
+                    buf[write..write + to.len()].copy_from_slice(to);
+                    read -= from.len();
+                } else {
+                    read -= 1;
+                    write -= 1;
+                    buf[write] = buf[read];
+                }
+            }
+        }
+        self.0.set_len(new_len);
+        Ok(())
+    }
+
+    /// Replace the content of `dst` with this string where all matches of `from` are replaced by `to`.
+    ///
+    /// Matches are found from left to right and do not overlap, as with `str::replace`.
+    /// Returns an error if the capacity of `dst` is insufficient.
+    /// `dst` then contains the part of the result that fit.
+    pub fn replace_str_into(
+        &self,
+        from: &str,
+        to: &str,
+        dst: &mut SliceString<'_>,
+    ) -> Result<(), Error> {
+        dst.clear();
+        let mut last = 0;
+        for (start, part) in self.match_indices(from) {
+            dst.try_push_str(&self[last..start])?;
+            dst.try_push_str(to)?;
+            last = start + part.len();
+        }
+        dst.try_push_str(&self[last..])
+    }
+
+    /// Return an iterator over the lines of the string.
+    ///
+    /// Lines are terminated by `\n` or `\r\n` which are not included in the items.
+    ///
+    /// ```
+    /// # use slice_string::SliceString;
+    /// let mut buf = *b"OK\r\n+CSQ: 20,99\r\n";
+    /// let response = SliceString::try_from(&mut buf[..]).unwrap();
+    /// let mut lines = response.lines();
+    /// assert_eq!(lines.next(), Some("OK"));
+    /// assert_eq!(lines.next(), Some("+CSQ: 20,99"));
+    /// assert_eq!(lines.next(), None);
+    /// ```
+    pub fn lines(&self) -> Lines<'_> {
+        Lines::new(self)
+    }
+
+    /// Return the number of lines in the string.
+    ///
+    /// This is `O(n)`.
+    pub fn lines_count(&self) -> usize {
+        self.lines().count()
+    }
+
+    /// Split the string into its lines and store them in `out`.
+    ///
+    /// Lines are split like [`SliceString::lines`] and the terminators are removed.
+    /// The string is consumed and each line borrows its own disjoint part of the
+    /// buffer for `'a`. The capacity of each line is its length.
+    /// Returns the number of lines stored. Lines that do not fit into `out` are dropped.
+    ///
+    /// ```
+    /// # use slice_string::SliceString;
+    /// let mut buf = *b"+CSQ: 20,99\r\nOK\r\n";
+    /// let rx = SliceString::try_from(&mut buf[..]).unwrap();
+    /// let mut lines: [SliceString; 4] = Default::default();
+    /// let n = rx.split_lines_into(&mut lines);
+    /// assert_eq!(&lines[..n], ["+CSQ: 20,99", "OK"]);
+    /// lines[1].make_ascii_lowercase();
+    /// assert_eq!(lines[1], "ok");
+    /// ```
+    pub fn split_lines_into(self, out: &mut [SliceString<'a>]) -> usize {
+        let (buf, len) = self.into();
+        let mut rest = &mut buf[..len];
+        let mut n = 0;
+        for slot in out.iter_mut() {
+            if rest.is_empty() {
+                break;
+            }
+            let data = core::mem::take(&mut rest);
+            let line = match data.iter().position(|&b| b == b'\n') {
+                Some(i) => {
+                    let (line, tail) = data.split_at_mut(i);
+                    rest = &mut tail[1..];
+                    match line {
+                        [line @ .., b'\r'] => line,
+                        line => line,
+                    }
+                }
+                None => data,
+            };
+            // Splitting at ASCII bytes maintains UTF-8 validity
+            *slot = unsafe { Self::from_utf8_unchecked(line, line.len()) };
+            n += 1;
+        }
+        n
+    }
+
+    /// Return an iterator over the words of the string, split by ASCII whitespace.
+    ///
+    /// Runs of whitespace and leading or trailing whitespace yield no empty words.
+    ///
+    /// ```
+    /// # use slice_string::SliceString;
+    /// let mut buf = *b"set  gain 3 \r\n";
+    /// let cmd = SliceString::try_from(&mut buf[..]).unwrap();
+    /// let mut words = cmd.words();
+    /// assert_eq!(words.next(), Some("set"));
+    /// let value: u8 = words.next_back().unwrap().parse().unwrap();
+    /// assert_eq!(value, 3);
+    /// assert_eq!(words.next(), Some("gain"));
+    /// assert_eq!(words.next(), None);
+    /// ```
+    pub fn words(&self) -> Words<'_> {
+        Words::new(self)
+    }
+
+    /// Return an error if fewer than `additional` bytes can be appended.
+    fn check_capacity(&self, additional: usize) -> Result<(), Error> {
+        let available = self.remaining_capacity();
+        if available < additional {
+            return Err(Error {
+                needed: additional,
+                available,
+            });
+        }
+        Ok(())
+    }
+
+    /// Append a `str` or return an error if the remaining space is insufficient.
+    fn try_push_str(&mut self, string: &str) -> Result<(), Error> {
+        self.check_capacity(string.len())?;
+        self.push_str(string);
+        Ok(())
+    }
+
+    /// Return the entire backing buffer including the unused capacity.
+    fn buf_mut(&mut self) -> &mut [u8] {
+        let len = self.capacity();
+        // The SliceVec holds a unique borrow of `capacity` bytes.
+        unsafe { core::slice::from_raw_parts_mut(self.0.as_mut_ptr(), len) }
+    }
+
+    /// Append formatted text or nothing if the remaining space is insufficient.
+    fn push_fmt(&mut self, args: fmt::Arguments<'_>) -> Result<(), Error> {
+        let mut len = ByteCounter(0);
+        fmt::write(&mut len, args).ok();
+        self.check_capacity(len.0)?;
+        fmt::write(self, args).ok();
+        Ok(())
+    }
+
+    /// Append the digits of `n` in `radix`, zero padded to at least `min_digits`,
+    /// optionally preceded by a `-` sign.
+    fn push_digits(
+        &mut self,
+        negative: bool,
+        mut n: u64,
+        radix: u8,
+        min_digits: usize,
+        uppercase: bool,
+    ) -> Result<(), Error> {
+        assert!((2..=36).contains(&radix), "radix must be in 2..=36");
+        let radix = radix as u64;
+        let mut digits = 1;
+        let mut m = n / radix;
+        while m > 0 {
+            digits += 1;
+            m /= radix;
+        }
+        let width = (negative as usize).saturating_add(digits.max(min_digits));
+        self.check_capacity(width)?;
+        let table = if uppercase {
+            b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ"
+        } else {
+            b"0123456789abcdefghijklmnopqrstuvwxyz"
+        };
+        let len = self.len();
+        let buf = &mut self.buf_mut()[len..len + width];
+        for b in buf.iter_mut().rev() {
+            *b = table[(n % radix) as usize];
+            n /= radix;
+        }
+        if negative {
+            buf[0] = b'-';
+        }
+        self.0.set_len(len + width);
+        Ok(())
+    }
+
+    /// Return the byte offset of the `n`th `char`, or the length if `n` is the number of chars.
+    fn char_offset(&self, n: usize) -> Option<usize> {
+        self.char_boundaries().nth(n)
+    }
+
+    /// Resolve a byte range to `(start, end)`.
+    ///
+    /// # Panics
+    /// The range must be ordered, within the string, and at character boundaries.
+    fn range_bounds<R: ops::RangeBounds<usize>>(&self, range: R) -> (usize, usize) {
+        let start = match range.start_bound() {
+            ops::Bound::Included(&n) => n,
+            ops::Bound::Excluded(&n) => n.checked_add(1).unwrap(),
+            ops::Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            ops::Bound::Included(&n) => n.checked_add(1).unwrap(),
+            ops::Bound::Excluded(&n) => n,
+            ops::Bound::Unbounded => self.len(),
+        };
+        assert!(start <= end && end <= self.len());
+        assert!(self.is_char_boundary(start) && self.is_char_boundary(end));
+        (start, end)
+    }
+
+    /// Remove the bytes in `start..end`, shifting the tail to the left.
+    pub(crate) fn remove_bytes(&mut self, start: usize, end: usize) {
+        let len = self.len();
+        self.0.copy_within(end..len, start);
+        self.0.truncate(len - (end - start));
+    }
+
+    /// Insert UTF-8 `bytes` at position `at`, shifting the tail to the right.
+    fn insert_bytes(&mut self, at: usize, bytes: &[u8]) -> Result<(), Error> {
+        assert!(self.is_char_boundary(at));
+        self.splice(at, at, bytes)
+    }
+
+    /// Replace the bytes in `start..end` with UTF-8 `bytes`, shifting the tail.
+    ///
+    /// `start` and `end` must be ordered character boundaries within the string.
+    fn splice(&mut self, start: usize, end: usize, bytes: &[u8]) -> Result<(), Error> {
+        self.check_capacity(bytes.len().saturating_sub(end - start))?;
+        let len = self.len();
+        let new_end = start + bytes.len();
+        let buf = self.buf_mut();
+        buf.copy_within(end..len, new_end);
+        buf[start..new_end].copy_from_slice(bytes);
+        self.0.set_len(len - (end - start) + bytes.len());
+        Ok(())
     }
 }
 
-impl<'a> hash::Hash for SliceString<'a> {
-    fn hash<H: hash::Hasher>(&self, hasher: &mut H) {
-        <str as hash::Hash>::hash(self, hasher)
+/// Collect `char`s into a new `SliceString` backed by `buf`.
+///
+/// This is the fixed-capacity alternative to `FromIterator<char>` which cannot be
+/// implemented as it has no way to receive a buffer.
+/// Collection stops at the first `char` that does not fit. That `char` is returned
+/// together with the string. The iterator is not advanced any further.
+pub fn collect_chars_into<I: IntoIterator<Item = char>>(
+    iter: I,
+    buf: &mut [u8],
+) -> (SliceString<'_>, Option<char>) {
+    let mut s = SliceString::new(buf);
+    for c in iter {
+        if s.remaining_capacity() < c.len_utf8() {
+            return (s, Some(c));
+        }
+        s.push(c);
+    }
+    (s, None)
+}
+
+/// Append a `str` to a `SliceString` and return it.
+///
+/// This is the fallible alternative to `a + b`.
+pub fn concat<'a>(mut a: SliceString<'a>, b: &str) -> Result<SliceString<'a>, Error> {
+    a.check_capacity(b.len())?;
+    a.push_str(b);
+    Ok(a)
+}
+
+/// Replace the content of `dst` with `parts` joined by `sep`.
+///
+/// This is the `no_std` alternative to `parts.join(sep)`.
+/// Returns an error and leaves `dst` empty if its capacity is insufficient.
+///
+/// ```
+/// # use slice_string::{join_into, SliceString};
+/// let mut buf = [0; 32];
+/// let mut topic = SliceString::new(&mut buf[..]);
+/// join_into(&["dt", "sinara", "stabilizer"], "/", &mut topic).unwrap();
+/// assert_eq!(topic, "dt/sinara/stabilizer");
+/// ```
+pub fn join_into(parts: &[&str], sep: &str, dst: &mut SliceString<'_>) -> Result<(), Error> {
+    dst.clear();
+    let len =
+        parts.iter().map(|p| p.len()).sum::<usize>() + sep.len() * parts.len().saturating_sub(1);
+    dst.check_capacity(len)?;
+    for (i, part) in parts.iter().enumerate() {
+        if i > 0 {
+            dst.push_str(sep);
+        }
+        dst.push_str(part);
+    }
+    Ok(())
+}
+
+/// Replace the content of `dst` with the concatenation of `parts`.
+///
+/// This is the `no_std` alternative to `parts.concat()`.
+/// Returns an error and leaves `dst` empty if its capacity is insufficient.
+pub fn concat_into(parts: &[&str], dst: &mut SliceString<'_>) -> Result<(), Error> {
+    join_into(parts, "", dst)
+}
+
+/// Parse a value from the content of a `SliceString`.
+///
+/// This is the same as `s.parse::<T>()` through `Deref<Target = str>`.
+/// `SliceString` itself does not implement `FromStr` as it cannot be created
+/// without a buffer.
+///
+/// ```
+/// # use slice_string::{parse_from_str, SliceString};
+/// let mut buf = *b"21.5";
+/// let s = SliceString::try_from(&mut buf[..]).unwrap();
+/// let t: f32 = parse_from_str(&s).unwrap();
+/// assert_eq!(t, 21.5);
+/// assert!(parse_from_str::<u8>(&s).is_err());
+/// ```
+pub fn parse_from_str<T: str::FromStr>(s: &SliceString<'_>) -> Result<T, T::Err> {
+    s.as_str().parse()
+}
+
+/// Format into a new `SliceString` backed by `buf`.
+///
+/// The output is truncated if it does not fit, see [`SliceString::write_fmt_lossy`].
+/// The [`format_into!`] macro provides a more convenient interface.
+pub fn format_into<'a>(buf: &'a mut [u8], args: fmt::Arguments<'_>) -> SliceString<'a> {
+    let mut s = SliceString::new(buf);
+    s.write_fmt_lossy(args);
+    s
+}
+
+/// Format into a new `SliceString` backed by a buffer.
+///
+/// The output is truncated if it does not fit.
+///
+/// ```
+/// # use slice_string::format_into;
+/// let mut buf = [0; 32];
+/// let temp = 21;
+/// let s = format_into!(&mut buf[..], "temperature: {}°C", temp);
+/// assert_eq!(s, "temperature: 21°C");
+/// ```
+#[macro_export]
+macro_rules! format_into {
+    ($buf:expr, $($arg:tt)*) => {
+        $crate::format_into($buf, ::core::format_args!($($arg)*))
+    };
+}
+
+/// Return the largest character boundary in `s` at or below `index`.
+fn floor_char_boundary(s: &str, index: usize) -> usize {
+    if index >= s.len() {
+        s.len()
+    } else {
+        // A char boundary is at most three bytes back and `0` is always a boundary.
+        (0..=index).rev().find(|&i| s.is_char_boundary(i)).unwrap()
+    }
+}
+
+impl<'a> From<SliceString<'a>> for SliceVec<'a, u8> {
+    fn from(value: SliceString<'a>) -> Self {
+        value.0
+    }
+}
+
+impl<'a> From<SliceString<'a>> for (&'a mut [u8], usize) {
+    fn from(mut value: SliceString<'a>) -> Self {
+        let data = value.as_mut_ptr();
+        let len = value.capacity();
+        // Unfortunately there is no way to destructure SliceVec
+        let data = unsafe { core::slice::from_raw_parts_mut(data, len) };
+        (data, value.len())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> From<SliceString<'a>> for String {
+    fn from(value: SliceString<'a>) -> Self {
+        value.into_string()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> From<&SliceString<'a>> for String {
+    fn from(value: &SliceString<'a>) -> Self {
+        String::from(value.as_str())
+    }
+}
+
+impl<'a> TryFrom<&'a mut [u8]> for SliceString<'a> {
+    type Error = str::Utf8Error;
+
+    fn try_from(value: &'a mut [u8]) -> Result<Self, Self::Error> {
+        Self::from_utf8(value, value.len())
+    }
+}
+
+impl<'a> TryFrom<SliceVec<'a, u8>> for SliceString<'a> {
+    type Error = str::Utf8Error;
+
+    fn try_from(value: SliceVec<'a, u8>) -> Result<Self, Self::Error> {
+        str::from_utf8(&value)?;
+        // UTF-8 validity has just been checked.
+        Ok(unsafe { Self::new_unchecked(value) })
+    }
+}
+
+impl<'a> TryFrom<(&str, &'a mut [u8])> for SliceString<'a> {
+    type Error = Error;
+
+    fn try_from((src, buf): (&str, &'a mut [u8])) -> Result<Self, Self::Error> {
+        Self::try_from_str(src, buf)
+    }
+}
+
+impl<'a> ops::Deref for SliceString<'a> {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<'a> ops::DerefMut for SliceString<'a> {
+    fn deref_mut(&mut self) -> &mut str {
+        self.as_mut_str()
+    }
+}
+
+impl<'a> AsRef<str> for SliceString<'a> {
+    fn as_ref(&self) -> &str {
+        self
+    }
+}
+
+impl<'a> AsMut<str> for SliceString<'a> {
+    fn as_mut(&mut self) -> &mut str {
+        self
+    }
+}
+
+impl<'a> borrow::Borrow<str> for SliceString<'a> {
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<'a> borrow::BorrowMut<str> for SliceString<'a> {
+    fn borrow_mut(&mut self) -> &mut str {
+        self.as_mut_str()
+    }
+}
+
+impl<'a> AsRef<SliceVec<'a, u8>> for SliceString<'a> {
+    fn as_ref(&self) -> &SliceVec<'a, u8> {
+        &self.0
+    }
+}
+
+impl<'a> AsRef<[u8]> for SliceString<'a> {
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_slice()
+    }
+}
+
+impl<'a> fmt::Write for SliceString<'a> {
+    fn write_str(&mut self, s: &str) -> Result<(), fmt::Error> {
+        if self.capacity() < self.len() + s.len() {
+            return Err(fmt::Error);
+        }
+        self.push_str(s);
+        Ok(())
+    }
+
+    fn write_char(&mut self, c: char) -> Result<(), fmt::Error> {
+        if self.capacity() < self.len() + c.len_utf8() {
+            return Err(fmt::Error);
+        }
+        self.push(c);
+        Ok(())
+    }
+}
+
+impl<'a> StringBuf for SliceString<'a> {
+    fn push(&mut self, c: char) -> Result<(), Error> {
+        self.try_push_str(c.encode_utf8(&mut [0; 4]))
+    }
+
+    fn push_str(&mut self, string: &str) -> Result<(), Error> {
+        self.try_push_str(string)
+    }
+
+    fn clear(&mut self) {
+        SliceString::clear(self)
+    }
+
+    fn capacity(&self) -> usize {
+        SliceString::capacity(self)
+    }
+}
+
+/// A `fmt::Write` implementation counting the number of bytes written.
+struct ByteCounter(usize);
+
+impl fmt::Write for ByteCounter {
+    fn write_str(&mut self, s: &str) -> Result<(), fmt::Error> {
+        self.0 += s.len();
+        Ok(())
+    }
+}
+
+/// A `fmt::Write` adapter that truncates the output at the capacity.
+struct LossyWriter<'s, 'a>(&'s mut SliceString<'a>);
+
+impl<'s, 'a> fmt::Write for LossyWriter<'s, 'a> {
+    fn write_str(&mut self, s: &str) -> Result<(), fmt::Error> {
+        if self.0.push_str_lossy(s) < s.len() {
+            return Err(fmt::Error);
+        }
+        Ok(())
+    }
+}
+
+impl<'a> fmt::Debug for SliceString<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        <str as fmt::Debug>::fmt(self, f)
+    }
+}
+
+impl<'a> fmt::Display for SliceString<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        <str as fmt::Display>::fmt(self, f)
+    }
+}
+
+/// Hash like `str`.
+///
+/// This is consistent with `Eq` and `Borrow<str>` so that a map keyed by
+/// `SliceString` can be queried with `&str`.
+///
+/// ```
+/// # use slice_string::SliceString;
+/// # use std::collections::HashMap;
+/// let mut buf = *b"gain";
+/// let mut settings = HashMap::new();
+/// settings.insert(SliceString::try_from(&mut buf[..]).unwrap(), 3);
+/// assert_eq!(settings.get("gain"), Some(&3));
+/// ```
+impl<'a> hash::Hash for SliceString<'a> {
+    fn hash<H: hash::Hasher>(&self, hasher: &mut H) {
+        <str as hash::Hash>::hash(self, hasher)
+    }
+}
+
+impl<'a, 'b> PartialEq<SliceString<'b>> for SliceString<'a> {
+    fn eq(&self, rhs: &SliceString<'b>) -> bool {
+        str::eq(&**self, &**rhs)
+    }
+}
+
+// SliceString<'a> == str
+impl<'a> PartialEq<str> for SliceString<'a> {
+    fn eq(&self, other: &str) -> bool {
+        str::eq(&self[..], other)
+    }
+}
+
+// SliceString<'a> == &'str
+impl<'a> PartialEq<&str> for SliceString<'a> {
+    fn eq(&self, other: &&str) -> bool {
+        str::eq(&self[..], &other[..])
+    }
+}
+
+// str == SliceString<'a>
+impl<'a> PartialEq<SliceString<'a>> for str {
+    fn eq(&self, other: &SliceString<'a>) -> bool {
+        str::eq(self, &other[..])
+    }
+}
+
+// &'str == SliceString<'a>
+impl<'a> PartialEq<SliceString<'a>> for &str {
+    fn eq(&self, other: &SliceString<'a>) -> bool {
+        str::eq(&self[..], &other[..])
+    }
+}
+
+// SliceString<'a> == String
+#[cfg(feature = "alloc")]
+impl<'a> PartialEq<String> for SliceString<'a> {
+    fn eq(&self, other: &String) -> bool {
+        str::eq(&self[..], &other[..])
+    }
+}
+
+// String == SliceString<'a>
+#[cfg(feature = "alloc")]
+impl<'a> PartialEq<SliceString<'a>> for String {
+    fn eq(&self, other: &SliceString<'a>) -> bool {
+        str::eq(&self[..], &other[..])
+    }
+}
+
+impl<'a> Eq for SliceString<'a> {}
+
+impl<'a> PartialOrd for SliceString<'a> {
+    fn partial_cmp(&self, other: &SliceString<'a>) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// SliceString<'a> <> str
+impl<'a> PartialOrd<str> for SliceString<'a> {
+    fn partial_cmp(&self, other: &str) -> Option<core::cmp::Ordering> {
+        PartialOrd::partial_cmp(&self[..], other)
+    }
+}
+
+// SliceString<'a> <> &'str
+impl<'a> PartialOrd<&str> for SliceString<'a> {
+    fn partial_cmp(&self, other: &&str) -> Option<core::cmp::Ordering> {
+        PartialOrd::partial_cmp(&self[..], *other)
+    }
+}
+
+// str <> SliceString<'a>
+impl<'a> PartialOrd<SliceString<'a>> for str {
+    fn partial_cmp(&self, other: &SliceString<'a>) -> Option<core::cmp::Ordering> {
+        PartialOrd::partial_cmp(self, &other[..])
+    }
+}
+
+// &'str <> SliceString<'a>
+impl<'a> PartialOrd<SliceString<'a>> for &str {
+    fn partial_cmp(&self, other: &SliceString<'a>) -> Option<core::cmp::Ordering> {
+        PartialOrd::partial_cmp(*self, &other[..])
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> PartialOrd<String> for SliceString<'a> {
+    fn partial_cmp(&self, other: &String) -> Option<core::cmp::Ordering> {
+        PartialOrd::partial_cmp(&self[..], &other[..])
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> PartialOrd<SliceString<'a>> for String {
+    fn partial_cmp(&self, other: &SliceString<'a>) -> Option<core::cmp::Ordering> {
+        PartialOrd::partial_cmp(&self[..], &other[..])
+    }
+}
+
+impl<'a> Ord for SliceString<'a> {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        Ord::cmp(&**self, &**other)
+    }
+}
+
+impl<'s, 'a> IntoIterator for &'s SliceString<'a> {
+    type Item = char;
+    type IntoIter = str::Chars<'s>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.chars()
+    }
+}
+
+impl<'s, 'a> IntoIterator for &'s mut SliceString<'a> {
+    type Item = char;
+    type IntoIter = str::Chars<'s>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.chars()
+    }
+}
+
+/// Append the `char`s of an iterator.
+///
+/// # Panics
+/// The remaining space must be sufficient.
+/// See [`SliceString::try_extend_chars`] for a fallible alternative.
+impl<'a> Extend<char> for SliceString<'a> {
+    fn extend<T: IntoIterator<Item = char>>(&mut self, iter: T) {
+        let iterator = iter.into_iter();
+        iterator.for_each(move |c| self.push(c));
+    }
+}
+
+impl<'a> Extend<&'a char> for SliceString<'a> {
+    fn extend<I: IntoIterator<Item = &'a char>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().cloned());
+    }
+}
+
+/// Append the `str`s of an iterator.
+///
+/// # Panics
+/// The remaining space must be sufficient.
+impl<'a, 'b> Extend<&'b str> for SliceString<'a> {
+    fn extend<T: IntoIterator<Item = &'b str>>(&mut self, iter: T) {
+        iter.into_iter().for_each(move |s| self.push_str(s));
+    }
+}
+
+/// Append a `str` to the string.
+///
+/// # Panics
+/// The remaining space must be sufficient. See [`concat()`] for a fallible alternative.
+impl<'a> ops::Add<&str> for SliceString<'a> {
+    type Output = SliceString<'a>;
+    #[inline]
+    fn add(mut self, rhs: &str) -> Self::Output {
+        self.push_str(rhs);
+        self
+    }
+}
+
+/// Append a `str` to the string.
+///
+/// # Panics
+/// The remaining space must be sufficient.
+/// The panic message reports the needed and the available number of bytes.
+impl<'a> ops::AddAssign<&str> for SliceString<'a> {
+    #[inline]
+    fn add_assign(&mut self, rhs: &str) {
+        self.push_str(rhs);
+    }
+}
+
+/// Index the string like a `str`.
+///
+/// This covers all range types: `..`, `a..`, `..b`, `..=b`, `a..b`, and `a..=b`.
+///
+/// # Panics
+/// Panics if a range bound is out of bounds or not at a character boundary.
+///
+/// ```
+/// # use slice_string::SliceString;
+/// let mut buf = *b"ADC0=1.25";
+/// let s = SliceString::try_from(&mut buf[..]).unwrap();
+/// assert_eq!(&s[..4], "ADC0");
+/// assert_eq!(&s[5..], "1.25");
+/// assert_eq!(&s[3..=3], "0");
+/// assert_eq!(&s[..=4], "ADC0=");
+/// ```
+impl<'a, I: slice::SliceIndex<str>> ops::Index<I> for SliceString<'a> {
+    type Output = I::Output;
+
+    #[inline]
+    fn index(&self, index: I) -> &I::Output {
+        self.as_str().index(index)
+    }
+}
+
+/// Mutably index the string like a `str`.
+///
+/// # Panics
+/// Panics if a range bound is out of bounds or not at a character boundary.
+impl<'a, I: slice::SliceIndex<str>> ops::IndexMut<I> for SliceString<'a> {
+    #[inline]
+    fn index_mut(&mut self, index: I) -> &mut I::Output {
+        self.as_mut_str().index_mut(index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::fmt::Write;
+
+    #[test]
+    fn new() {
+        let mut buf = [0u8; 16];
+        let mut s = SliceString::new(&mut buf[..]);
+        assert_eq!(0, s.len());
+        assert_eq!(s.capacity(), 16);
+
+        s.push_str("Hello world!");
+        assert_eq!(s.as_str(), "Hello world!");
+
+        assert!(!s.is_empty());
+        s.clear();
+        assert_eq!(s.len(), 0);
+
+        s.push_str("foo");
+        s.truncate(2);
+        assert_eq!(s.len(), 2);
+        assert_eq!(s.as_str(), "fo");
+
+        s.push('r');
+        assert_eq!(s.as_str(), "for");
+
+        s.write_str("oooooooooooooooooooooo").unwrap_err();
+
+        let mut a = s.split_off(2);
+        assert_eq!(s.as_str(), "fo");
+        assert_eq!(a.as_str(), "r");
+
+        a.push_str("ab");
+        s.push_str("");
+        s.write_char('o').unwrap_err();
+        assert_eq!(s.capacity(), 2);
+        assert_eq!(a.capacity(), 16 - 2);
+
+        let r = unsafe { s.as_mut_slicevec() };
+        assert_eq!(r.as_ref(), "fo".as_bytes());
+
+        assert_eq!(s.pop().unwrap(), 'o');
+        assert_eq!(s.pop().unwrap(), 'f');
+    }
+
+    #[test]
+    #[should_panic]
+    fn panic_push() {
+        let mut buf = [0u8; 1];
+        let mut s = SliceString::new(&mut buf[..]);
+        s.push('f');
+        s.push('o');
+    }
+
+    #[test]
+    #[should_panic(expected = "SliceString full: needed 1, available 0")]
+    fn panic_push_ascii_message() {
+        let mut buf = [0u8; 2];
+        let mut s = SliceString::new(&mut buf[..]);
+        s.push('ä');
+        s.push('o');
+    }
+
+    #[test]
+    #[should_panic]
+    fn panic_push_str() {
+        let mut buf = [0u8; 1];
+        let mut s = SliceString::new(&mut buf[..]);
+        s.push_str("fo");
+    }
+
+    #[test]
+    fn cmp() {
+        let mut b1 = "abcd".as_bytes().to_owned();
+        let s1 = SliceString::try_from(&mut b1[..]).unwrap();
+        let mut b2 = "zzzz".as_bytes().to_owned();
+        let s2 = SliceString::try_from(&mut b2[..]).unwrap();
+        assert!(s1 < s2);
+    }
+
+    #[test]
+    fn disp() {
+        let mut b1 = "abcd".as_bytes().to_owned();
+        let s1 = SliceString::try_from(&mut b1[..]).unwrap();
+        let mut s = String::new();
+        write!(s, "{}", s1).unwrap();
+        assert_eq!("abcd", s);
+    }
+
+    #[test]
+    fn pop_uenc() {
+        let mut b = "éé".as_bytes().to_owned();
+        assert_eq!(b.len(), 2 + 3);
+        let mut s = SliceString::try_from(&mut b[..]).unwrap();
+        assert_eq!(s.len(), 2 + 3);
+        assert_eq!(s.pop().unwrap(), '\u{0301}');
+        assert_eq!(s.len(), 2 + 1);
+        assert_eq!(s.pop().unwrap(), 'e');
+        assert_eq!(s.len(), 2);
+        assert_eq!(s.pop().unwrap(), 'é');
+        assert_eq!(s.len(), 0);
+        s.push('ö');
+        s.push_str("ü");
+        assert_eq!(s.as_str(), "öü");
+    }
+
+    #[test]
+    fn write() {
+        let mut b = [0; 8];
+        let mut s = SliceString::new(&mut b[..]);
+        s.write_str("a").unwrap();
+        s.write_char('b').unwrap();
+        write!(s, "cdefgh").unwrap();
+        assert_eq!(s.len(), 8);
+        write!(s, "").unwrap();
+        write!(s, "a").unwrap_err();
+    }
+
+    #[test]
+    fn extend() {
+        let mut b = [0; 8];
+        let q = ["foo"];
+        let mut i = q.iter().copied();
+        let ii = &mut i;
+        {
+            let mut s = SliceString::new(&mut b[..]);
+            s.extend(ii);
+            println!("{}", s + "d");
+        }
+        println!("{:?}", i.next());
+        println!("{}", q[0])
+    }
+
+    #[test]
+    fn insert() {
+        let mut b = [0; 8];
+        let mut s = SliceString::new(&mut b[..]);
+        s.insert(0, 'b').unwrap();
+        s.insert(0, 'a').unwrap();
+        s.insert(2, 'c').unwrap();
+        assert_eq!(s, "abc");
+        s.insert(1, 'é').unwrap();
+        assert_eq!(s, "aébc");
+        s.insert(s.len(), '€').unwrap();
+        assert_eq!(s, "aébc€");
+        assert_eq!(s.len(), 8);
+        assert_eq!(
+            s.insert(0, 'x'),
+            Err(Error {
+                needed: 1,
+                available: 0
+            })
+        );
+        assert_eq!(s, "aébc€");
+    }
+
+    #[test]
+    #[should_panic]
+    fn panic_insert() {
+        let mut b = "é".as_bytes().to_owned();
+        b.push(0);
+        let mut s = SliceString::from_utf8(&mut b[..], 2).unwrap();
+        s.insert(1, 'a').ok();
+    }
+
+    #[test]
+    fn insert_str() {
+        let mut b = [0; 14];
+        let mut s = SliceString::new(&mut b[..]);
+        s.insert_str(0, "bar").unwrap();
+        s.insert_str(0, "foo").unwrap();
+        s.insert_str(s.len(), "").unwrap();
+        s.insert_str(3, "äö").unwrap();
+        assert_eq!(s, "fooäöbar");
+        s.insert_str(s.len(), "€").unwrap();
+        assert_eq!(s, "fooäöbar€");
+        assert_eq!(
+            s.insert_str(0, "ab"),
+            Err(Error {
+                needed: 2,
+                available: 1
+            })
+        );
+        s.insert_str(0, "a").unwrap();
+        assert_eq!(s, "afooäöbar€");
+    }
+
+    #[test]
+    fn remove() {
+        let mut b = "aé€𝄞z".as_bytes().to_owned();
+        let mut s = SliceString::try_from(&mut b[..]).unwrap();
+        assert_eq!(s.remove(0), 'a');
+        assert_eq!(s, "é€𝄞z");
+        assert_eq!(s.remove(5), '𝄞');
+        assert_eq!(s, "é€z");
+        assert_eq!(s.remove(2), '€');
+        assert_eq!(s.remove(2), 'z');
+        assert_eq!(s.remove(0), 'é');
+        assert!(s.is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn panic_remove() {
+        let mut b = "é".as_bytes().to_owned();
+        let mut s = SliceString::try_from(&mut b[..]).unwrap();
+        s.remove(1);
+    }
+
+    #[test]
+    fn retain() {
+        let mut b = [0; 16];
+        let mut s = SliceString::new(&mut b[..]);
+        s.push_str("aé€b𝄞c");
+        s.retain(|_| true);
+        assert_eq!(s, "aé€b𝄞c");
+        s.retain(|c| !c.is_ascii());
+        assert_eq!(s, "é€𝄞");
+        s.clear();
+        s.push_str("aé€b𝄞c");
+        s.retain(|c| c.is_ascii());
+        assert_eq!(s, "abc");
+        s.retain(|_| false);
+        assert!(s.is_empty());
+    }
+
+    #[test]
+    fn replace_range() {
+        let mut b = [0; 10];
+        let mut s = SliceString::new(&mut b[..]);
+        s.push_str("foo bar");
+        s.replace_range(4.., "baz").unwrap();
+        assert_eq!(s, "foo baz");
+        s.replace_range(..3, "€").unwrap();
+        assert_eq!(s, "€ baz");
+        s.replace_range(0..3, "q").unwrap();
+        assert_eq!(s, "q baz");
+        s.replace_range(1..2, "uuxx_").unwrap();
+        assert_eq!(s, "quuxx_baz");
+        s.replace_range(..=0, "Qu").unwrap();
+        assert_eq!(s, "Quuuxx_baz");
+        assert_eq!(
+            s.replace_range(.., "0123456789a"),
+            Err(Error {
+                needed: 1,
+                available: 0
+            })
+        );
+        assert_eq!(s, "Quuuxx_baz");
+        s.replace_range(.., "").unwrap();
+        assert!(s.is_empty());
+    }
+
+    #[test]
+    fn remaining_capacity() {
+        let mut b = [0; 4];
+        let mut s = SliceString::new(&mut b[..]);
+        assert_eq!(s.remaining_capacity(), 4);
+        assert!(!s.is_full());
+        s.push('é');
+        assert_eq!(s.remaining_capacity(), 2);
+        assert!(!s.is_full());
+        s.push_str("ab");
+        assert_eq!(s.remaining_capacity(), 0);
+        assert!(s.is_full());
+        let mut b = [];
+        let s = SliceString::new(&mut b[..]);
+        assert!(s.is_full());
+    }
+
+    #[test]
+    fn error() {
+        let mut b = [0; 4];
+        let mut s = SliceString::new(&mut b[..]);
+        s.push_str("ab");
+        let err = s.insert_str(1, "cdefg").unwrap_err();
+        assert_eq!(err.needed, 5);
+        assert_eq!(err.available, 2);
+        assert_eq!(err.to_string(), "SliceString full: needed 5, available 2");
+    }
+
+    #[test]
+    fn error_trait() {
+        use core::error::Error as _;
+        let err = Error {
+            needed: 1,
+            available: 0,
+        };
+        assert!(err.source().is_none());
+        let _: Box<dyn core::error::Error> = Box::new(err);
+    }
+
+    #[test]
+    fn push_str_lossy() {
+        let mut b = [0; 10];
+        let mut s = SliceString::new(&mut b[..]);
+        assert_eq!(s.push_str_lossy("ab"), 2);
+        assert_eq!(s.push_str_lossy("€€€"), 6);
+        assert_eq!(s, "ab€€");
+        assert_eq!(s.push_str_lossy("𝄞é"), 0);
+        assert_eq!(s.push_str_lossy("aé"), 1);
+        assert_eq!(s.push_str_lossy("a"), 1);
+        assert_eq!(s.push_str_lossy("a"), 0);
+        assert_eq!(s, "ab€€aa");
+    }
+
+    #[test]
+    fn push_char_n() {
+        let mut b = [0; 8];
+        let mut s = SliceString::new(&mut b[..]);
+        assert_eq!(s.push_char_n(' ', 2), Ok(2));
+        assert_eq!(s.push_char_n('€', 0), Ok(0));
+        assert_eq!(s.push_char_n('é', 2), Ok(2));
+        assert_eq!(s, "  éé");
+        assert_eq!(
+            s.push_char_n('€', 1),
+            Err(Error {
+                needed: 3,
+                available: 2
+            })
+        );
+        assert!(s.push_char_n('0', usize::MAX).is_err());
+        assert_eq!(s.push_char_n('0', 2), Ok(2));
+        assert_eq!(s, "  éé00");
+    }
+
+    #[test]
+    fn truncate_to_char_boundary() {
+        let mut b = "aé€𝄞".as_bytes().to_owned();
+        let mut s = SliceString::try_from(&mut b[..]).unwrap();
+        assert_eq!(s.truncate_to_char_boundary(20), 10);
+        assert_eq!(s.truncate_to_char_boundary(9), 6);
+        assert_eq!(s, "aé€");
+        assert_eq!(s.truncate_to_char_boundary(5), 3);
+        assert_eq!(s.truncate_to_char_boundary(2), 1);
+        assert_eq!(s, "a");
+        assert_eq!(s.truncate_to_char_boundary(0), 0);
+        assert!(s.is_empty());
+    }
+
+    #[test]
+    fn try_extend_chars() {
+        let mut b = [0; 4];
+        let mut s = SliceString::new(&mut b[..]);
+        s.try_extend_chars("aé".chars()).unwrap();
+        assert_eq!(
+            s.try_extend_chars("b€".chars()),
+            Err(Error {
+                needed: 3,
+                available: 0
+            })
+        );
+        assert_eq!(s, "aéb");
+        s.clear();
+        s.extend(['a', 'b']);
+        assert_eq!(s, "ab");
+    }
+
+    #[test]
+    #[should_panic]
+    fn panic_extend_chars() {
+        let mut b = [0; 2];
+        let mut s = SliceString::new(&mut b[..]);
+        s.extend("abc".chars());
+    }
+
+    #[test]
+    fn extend_str() {
+        let mut b = [0; 9];
+        let mut s = SliceString::new(&mut b[..]);
+        s.extend(["foo", "bar", "baz"].iter().copied());
+        assert_eq!(s, "foobarbaz");
+        assert!(s.is_full());
+        let words = String::from("a b");
+        s.clear();
+        s.extend(words.split(' '));
+        assert_eq!(s, "ab");
+    }
+
+    #[test]
+    #[should_panic]
+    fn panic_extend_str() {
+        let mut b = [0; 8];
+        let mut s = SliceString::new(&mut b[..]);
+        s.extend(["foo", "bar", "baz"]);
+    }
+
+    #[test]
+    fn try_from_str() {
+        let mut b = [0; 4];
+        let s = SliceString::try_from_str("aé", &mut b[..]).unwrap();
+        assert_eq!(s, "aé");
+        assert_eq!(s.capacity(), 4);
+        let s = SliceString::try_from(("abcd", &mut b[..])).unwrap();
+        assert_eq!(s, "abcd");
+        assert_eq!(
+            SliceString::try_from_str("abcde", &mut b[..]),
+            Err(Error {
+                needed: 5,
+                available: 4
+            })
+        );
+    }
+
+    #[test]
+    fn copy_from_str() {
+        let mut b = [0; 4];
+        let mut s = SliceString::new(&mut b[..]);
+        s.push_str("ab");
+        s.copy_from_str("cdéf").unwrap_err();
+        assert!(s.is_empty());
+        s.copy_from_str("cé").unwrap();
+        assert_eq!(s, "cé");
+        s.copy_from_str("").unwrap();
+        assert!(s.is_empty());
+    }
+
+    #[test]
+    fn split_off_front() {
+        let mut b = [0; 8];
+        let mut s = SliceString::new(&mut b[..]);
+        s.push_str("aébc");
+        let head = s.split_off_front(3);
+        assert_eq!(head, "aé");
+        assert_eq!(head.capacity(), 3);
+        assert_eq!(s, "bc");
+        assert_eq!(s.capacity(), 5);
+        assert_eq!(s.split_off_front(0), "");
+        assert_eq!(s.split_off_front(2), "bc");
+        assert!(s.is_empty());
+        assert_eq!(s.capacity(), 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panic_split_off_front() {
+        let mut b = "é".as_bytes().to_owned();
+        let mut s = SliceString::try_from(&mut b[..]).unwrap();
+        s.split_off_front(1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panic_split_off_front_len() {
+        let mut b = *b"ab\xff\xfe\xc3zzz";
+        let mut s = SliceString::from_utf8(&mut b[..], 2).unwrap();
+        s.split_off_front(5);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panic_split_off_len() {
+        let mut b = [0; 4];
+        let mut s = SliceString::new(&mut b[..]);
+        s.push('a');
+        s.split_off(2);
+    }
+
+    #[test]
+    fn eq_lifetimes() {
+        let mut b1 = "abc".as_bytes().to_owned();
+        let s1 = SliceString::try_from(&mut b1[..]).unwrap();
+        let eq = |s2: &SliceString<'_>| s1 == *s2;
+        let mut b2 = [0; 4];
+        let mut s2 = SliceString::new(&mut b2[..]);
+        s2.push_str("abc");
+        assert!(eq(&s2));
+        s2.push('d');
+        assert!(!eq(&s2));
+    }
+
+    #[test]
+    fn borrow() {
+        use core::borrow::BorrowMut;
+        use std::collections::BTreeMap;
+
+        let mut b1 = "foo".as_bytes().to_owned();
+        let mut b2 = "bar".as_bytes().to_owned();
+        let mut m = BTreeMap::new();
+        m.insert(SliceString::try_from(&mut b1[..]).unwrap(), 1);
+        m.insert(SliceString::try_from(&mut b2[..]).unwrap(), 2);
+        assert_eq!(m.get("foo"), Some(&1));
+        assert_eq!(m.get("bar"), Some(&2));
+        assert_eq!(m.get("baz"), None);
+
+        let mut b = "abc".as_bytes().to_owned();
+        let mut s = SliceString::try_from(&mut b[..]).unwrap();
+        BorrowMut::<str>::borrow_mut(&mut s).make_ascii_uppercase();
+        assert_eq!(s, "ABC");
+    }
+
+    #[test]
+    fn index() {
+        let mut b = "aébc".as_bytes().to_owned();
+        let mut s = SliceString::try_from(&mut b[..]).unwrap();
+        assert_eq!(&s[..], "aébc");
+        assert_eq!(&s[1..3], "é");
+        assert_eq!(&s[1..=2], "é");
+        assert_eq!(&s[1..], "ébc");
+        assert_eq!(&s[..3], "aé");
+        assert_eq!(&s[..=2], "aé");
+        s[3..].make_ascii_uppercase();
+        assert_eq!(s, "aéBC");
+    }
+
+    #[test]
+    #[should_panic]
+    fn panic_index() {
+        let mut b = "é".as_bytes().to_owned();
+        let s = SliceString::try_from(&mut b[..]).unwrap();
+        let _ = &s[1..];
+    }
+
+    #[test]
+    fn get() {
+        let mut b = "aébc".as_bytes().to_owned();
+        let mut s = SliceString::try_from(&mut b[..]).unwrap();
+        assert_eq!(s.get(1..3), Some("é"));
+        assert_eq!(s.get(2..), None);
+        assert_eq!(s.get(..6), None);
+        s.get_mut(3..).unwrap().make_ascii_uppercase();
+        assert_eq!(s, "aéBC");
+        assert!(s.get_mut(..2).is_none());
+    }
+
+    #[test]
+    fn strip() {
+        let mut b = "HDR:body\r\n".as_bytes().to_owned();
+        let mut s = SliceString::try_from(&mut b[..]).unwrap();
+        assert_eq!(s.strip_prefix("HDR:"), Some("body\r\n"));
+        assert_eq!(s.strip_suffix("\r\n"), Some("HDR:body"));
+        assert_eq!(s.strip_prefix("body"), None);
+        assert_eq!(s.strip_prefix('H'), Some("DR:body\r\n"));
+        assert_eq!(s.strip_suffix(|c: char| c == '\n'), Some("HDR:body\r"));
+        assert!(!s.strip_prefix_in_place("body"));
+        assert!(s.strip_prefix_in_place("HDR:"));
+        assert_eq!(s, "body\r\n");
+        assert!(!s.strip_suffix_in_place("\n\r"));
+        assert!(s.strip_suffix_in_place("\r\n"));
+        assert_eq!(s, "body");
+        assert!(s.strip_prefix_in_place(""));
+        assert_eq!(s, "body");
+    }
+
+    #[test]
+    fn make_ascii_case() {
+        let mut b = "aBéÉ1".as_bytes().to_owned();
+        let mut s = SliceString::try_from(&mut b[..]).unwrap();
+        s.make_ascii_uppercase();
+        assert_eq!(s, "ABéÉ1");
+        assert_eq!(s.len(), 7);
+        s.make_ascii_lowercase();
+        assert_eq!(s, "abéÉ1");
+        assert_eq!(s.len(), 7);
+    }
+
+    #[test]
+    fn eq_ignore_ascii_case() {
+        let mut b = "AT+Cé".as_bytes().to_owned();
+        let s = SliceString::try_from(&mut b[..]).unwrap();
+        assert!(s.eq_ignore_ascii_case("at+cé"));
+        assert!(!s.eq_ignore_ascii_case("at+cÉ"));
+        assert!(!s.eq_ignore_ascii_case("at+c"));
+    }
+
+    #[test]
+    fn is_ascii() {
+        let mut b = "abcé".as_bytes().to_owned();
+        let mut s = SliceString::try_from(&mut b[..]).unwrap();
+        assert!(!s.is_ascii());
+        assert!(!s.chars_are_ascii());
+        s.pop();
+        assert!(s.is_ascii());
+        assert!(s.chars_are_ascii());
+        s.clear();
+        assert!(s.is_ascii());
+        assert!(s.chars_are_ascii());
+    }
+
+    #[test]
+    fn split_once() {
+        let mut b = "a=é=c".as_bytes().to_owned();
+        let s = SliceString::try_from(&mut b[..]).unwrap();
+        assert_eq!(s.split_once("="), Some(("a", "é=c")));
+        assert_eq!(s.rsplit_once("="), Some(("a=é", "c")));
+        assert_eq!(s.split_once("é"), Some(("a=", "=c")));
+        assert_eq!(s.split_once(":"), None);
+        assert_eq!(s.rsplit_once(":"), None);
+        assert_eq!(s.split_once('='), Some(("a", "é=c")));
+        assert_eq!(s.rsplit_once(['=', ':']), Some(("a=é", "c")));
+    }
+
+    #[test]
+    fn into_iter() {
+        let mut b = "aé€".as_bytes().to_owned();
+        let mut s = SliceString::try_from(&mut b[..]).unwrap();
+        let mut n = 0;
+        for c in &s {
+            assert!(c != 'x');
+            n += 1;
+        }
+        assert_eq!(n, 3);
+        assert!((&mut s).into_iter().eq("aé€".chars()));
+    }
+
+    #[test]
+    fn collect_chars_into() {
+        let mut b = [0; 4];
+        let (s, rest) = super::collect_chars_into("aé".chars(), &mut b[..]);
+        assert_eq!(s, "aé");
+        assert_eq!(rest, None);
+        let mut it = "ab€c".chars();
+        let (s, rest) = super::collect_chars_into(&mut it, &mut b[..]);
+        assert_eq!(s, "ab");
+        assert_eq!(rest, Some('€'));
+        assert_eq!(it.as_str(), "c");
+    }
+
+    #[test]
+    fn chars_count() {
+        let mut b = "aé€𝄞".as_bytes().to_owned();
+        let mut s = SliceString::try_from(&mut b[..]).unwrap();
+        assert_eq!(s.chars_count(), 4);
+        assert_eq!(s.len(), 10);
+        s.clear();
+        assert_eq!(s.chars_count(), 0);
+    }
+
+    #[test]
+    fn char_at() {
+        let mut b = "aé€𝄞".as_bytes().to_owned();
+        let s = SliceString::try_from(&mut b[..]).unwrap();
+        assert_eq!(s.char_at(0), Some('a'));
+        assert_eq!(s.char_at(2), Some('€'));
+        assert_eq!(s.char_at(3), Some('𝄞'));
+        assert_eq!(s.char_at(4), None);
+        assert_eq!(s.byte_index_of_char(0), Some(0));
+        assert_eq!(s.byte_index_of_char(2), Some(3));
+        assert_eq!(s.byte_index_of_char(3), Some(6));
+        assert_eq!(s.byte_index_of_char(4), None);
+    }
+
+    #[test]
+    fn split_at_char() {
+        let mut b = "aé€𝄞".as_bytes().to_owned();
+        let s = SliceString::try_from(&mut b[..]).unwrap();
+        assert_eq!(s.split_at_char(0), ("", "aé€𝄞"));
+        assert_eq!(s.split_at_char(2), ("aé", "€𝄞"));
+        assert_eq!(s.split_at_char(3), ("aé€", "𝄞"));
+        assert_eq!(s.split_at_char(4), ("aé€𝄞", ""));
+    }
+
+    #[test]
+    #[should_panic]
+    fn panic_split_at_char() {
+        let mut b = "é".as_bytes().to_owned();
+        let s = SliceString::try_from(&mut b[..]).unwrap();
+        s.split_at_char(2);
+    }
+
+    #[test]
+    fn pop_front() {
+        let mut b = "a€b".as_bytes().to_owned();
+        let mut s = SliceString::try_from(&mut b[..]).unwrap();
+        assert_eq!(s.pop_front(), Some('a'));
+        assert_eq!(s, "€b");
+        assert_eq!(s.pop_front(), Some('€'));
+        assert_eq!(s, "b");
+        assert_eq!(s.pop_front(), Some('b'));
+        assert_eq!(s.pop_front(), None);
+        assert!(s.is_empty());
+    }
+
+    #[test]
+    fn trim_in_place() {
+        let mut b = " \t a b\u{3000}\n".as_bytes().to_owned();
+        let mut s = SliceString::try_from(&mut b[..]).unwrap();
+        s.trim_end_in_place();
+        assert_eq!(s, " \t a b");
+        s.trim_start_in_place();
+        assert_eq!(s, "a b");
+        s.trim_in_place();
+        assert_eq!(s, "a b");
+        s.clear();
+        s.push_str(" \u{3000} a ");
+        s.trim_in_place();
+        assert_eq!(s, "a");
+        s.clear();
+        s.push_str(" \r\n ");
+        s.trim_in_place();
+        assert!(s.is_empty());
+    }
+
+    #[test]
+    fn trim_matches_in_place() {
+        let mut b = "\0\0a\0b\r\n\0".as_bytes().to_owned();
+        let mut s = SliceString::try_from(&mut b[..]).unwrap();
+        s.trim_end_matches_in_place('\0');
+        assert_eq!(s, "\0\0a\0b\r\n");
+        s.trim_end_matches_in_place(&['\r', '\n'][..]);
+        assert_eq!(s, "\0\0a\0b");
+        s.trim_start_matches_in_place('\0');
+        assert_eq!(s, "a\0b");
+        s.trim_matches_in_place(|c: char| c.is_alphabetic());
+        assert_eq!(s, "\0");
+        s.clear();
+        s.push_str("éaé");
+        s.trim_matches_in_place(['é']);
+        assert_eq!(s, "a");
+        s.trim_matches_in_place(&['a', 'b']);
+        assert!(s.is_empty());
+    }
+
+    #[test]
+    fn reverse_in_place() {
+        let mut b = "aé€𝄞b".as_bytes().to_owned();
+        let mut s = SliceString::try_from(&mut b[..]).unwrap();
+        s.reverse_in_place();
+        assert_eq!(s, "b𝄞€éa");
+        s.reverse_in_place();
+        assert_eq!(s, "aé€𝄞b");
+        s.truncate(1);
+        s.reverse_in_place();
+        assert_eq!(s, "a");
+        s.clear();
+        s.reverse_in_place();
+        assert!(s.is_empty());
+    }
+
+    #[test]
+    fn repeat_fill() {
+        let mut b = [0; 8];
+        let mut s = SliceString::new(&mut b[..]);
+        assert_eq!(s.repeat_fill("ab€"), 1);
+        assert_eq!(s, "ab€");
+        assert_eq!(s.repeat_fill("ab€"), 0);
+        assert_eq!(s.repeat_fill(""), 0);
+        assert_eq!(s.repeat_fill("-"), 3);
+        assert_eq!(s, "ab€---");
+        assert!(s.is_full());
+        s.clear();
+        assert_eq!(s.repeat_fill("0123456789"), 0);
+        assert!(s.is_empty());
+    }
+
+    #[test]
+    fn write_fmt_lossy() {
+        let mut b = [0; 8];
+        let mut s = SliceString::new(&mut b[..]);
+        assert_eq!(s.write_fmt_lossy(format_args!("{}€", 12)), 5);
+        assert_eq!(s.write_fmt_lossy(format_args!("{}{}", 'é', "€")), 2);
+        assert_eq!(s, "12€é");
+        assert_eq!(s.write_fmt_lossy(format_args!("{}", 1)), 1);
+        assert_eq!(s.write_fmt_lossy(format_args!("{}", 1)), 0);
+        assert_eq!(s, "12€é1");
+    }
+
+    #[test]
+    fn format_into() {
+        let mut b = [0; 8];
+        let s = super::format_into(&mut b[..], format_args!("{}-{}", 1, 2));
+        assert_eq!(s, "1-2");
+        let s = format_into!(&mut b[..], "{:>6}€", 'x');
+        assert_eq!(s, "     x");
+    }
+
+    #[test]
+    fn push_int() {
+        let mut b = [0; 64];
+        let mut s = SliceString::new(&mut b[..]);
+        s.push_int(0, 10).unwrap();
+        s.push(' ');
+        s.push_int(-1234, 10).unwrap();
+        s.push(' ');
+        s.push_int(i64::MIN, 10).unwrap();
+        s.push(' ');
+        s.push_int(-255, 16).unwrap();
+        s.push(' ');
+        s.push_uint(u64::MAX, 10).unwrap();
+        s.push(' ');
+        s.push_uint(35, 36).unwrap();
+        s.push(' ');
+        s.push_uint(5, 2).unwrap();
+        assert_eq!(
+            s,
+            "0 -1234 -9223372036854775808 -ff 18446744073709551615 z 101"
+        );
+        s.clear();
+        s.push_str(&"x".repeat(61));
+        assert_eq!(
+            s.push_int(-1000, 10),
+            Err(Error {
+                needed: 5,
+                available: 3
+            })
+        );
+        s.push_int(-10, 10).unwrap();
+        assert!(s.ends_with("x-10"));
+    }
+
+    #[test]
+    #[should_panic]
+    fn panic_push_int_radix() {
+        let mut b = [0; 8];
+        let mut s = SliceString::new(&mut b[..]);
+        s.push_uint(1, 37).ok();
+    }
+
+    #[test]
+    fn push_float() {
+        let mut b = [0; 64];
+        let mut s = SliceString::new(&mut b[..]);
+        for (v, dp) in [
+            (0.0, 0),
+            (1.5, 0),
+            (-2.25, 1),
+            (1.23456, 3),
+            (-0.0001, 2),
+            (1e20, 1),
+            (f64::NAN, 2),
+            (f64::INFINITY, 2),
+            (f64::NEG_INFINITY, 0),
+        ] {
+            s.push_float(v, dp).unwrap();
+            s.push(' ');
+        }
+        assert_eq!(
+            s,
+            "0 2 -2.2 1.235 -0.00 100000000000000000000.0 NaN inf -inf "
+        );
+        s.clear();
+        s.push_str(&"x".repeat(60));
+        assert_eq!(
+            s.push_float(-1.25, 2),
+            Err(Error {
+                needed: 5,
+                available: 4
+            })
+        );
+        assert_eq!(s.len(), 60);
+    }
+
+    #[test]
+    fn push_hex() {
+        let mut b = [0; 46];
+        let mut s = SliceString::new(&mut b[..]);
+        s.push_hex(0, false, 0).unwrap();
+        s.push(' ');
+        s.push_hex(0, false, 4).unwrap();
+        s.push(' ');
+        s.push_hex(0xbeef, true, 2).unwrap();
+        s.push(' ');
+        s.push_hex(0xbeef, false, 8).unwrap();
+        s.push(' ');
+        s.push_binary(0, 0).unwrap();
+        s.push(' ');
+        s.push_binary(5, 8).unwrap();
+        assert_eq!(s, "0 0000 BEEF 0000beef 0 00000101");
+        assert_eq!(
+            s.push_hex(u64::MAX, false, 0),
+            Err(Error {
+                needed: 16,
+                available: 15
+            })
+        );
+        assert!(s.push_binary(1, usize::MAX).is_err());
+        s.push_binary(u64::MAX, 0).unwrap_err();
+        s.push_hex(u64::MAX, true, 15).unwrap_err();
+        s.push_hex(u64::MAX >> 4, true, 15).unwrap();
+        assert!(s.is_full());
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn cmp_string() {
+        let mut b = "abc".as_bytes().to_owned();
+        let s = SliceString::try_from(&mut b[..]).unwrap();
+        let (abc, abd) = (String::from("abc"), String::from("abd"));
+        assert_eq!(s, abc);
+        assert_eq!(abc, s);
+        assert!(s != abd);
+        assert!(s < abd);
+        assert!(abd > s);
     }
-}
 
-impl<'a> PartialEq for SliceString<'a> {
-    fn eq(&self, rhs: &SliceString<'a>) -> bool {
-        str::eq(&**self, &**rhs)
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn into_string() {
+        let mut b = "aé€".as_bytes().to_owned();
+        let s = SliceString::try_from(&mut b[..]).unwrap();
+        assert_eq!(String::from(&s), "aé€");
+        let owned = s.into_string();
+        assert_eq!(owned, "aé€");
+        let mut b = [0; 8];
+        let s = SliceString::try_from_str(&owned, &mut b[..]).unwrap();
+        assert_eq!(String::from(s), owned);
     }
-}
 
-// SliceString<'a> == str
-impl<'a> PartialEq<str> for SliceString<'a> {
-    fn eq(&self, other: &str) -> bool {
-        str::eq(&self[..], other)
+    #[test]
+    fn clone_into() {
+        let mut b = "aé".as_bytes().to_owned();
+        let s = SliceString::try_from(&mut b[..]).unwrap();
+        let mut b1 = [0; 3];
+        let c = s.clone_into_buf(&mut b1[..]).unwrap();
+        assert_eq!(c, s);
+        assert_eq!(c.capacity(), 3);
+        let mut b2 = [0; 2];
+        assert!(s.clone_into_buf(&mut b2[..]).is_err());
+        let mut t = SliceString::new(&mut b2[..]);
+        assert!(s.clone_into_slice_string(&mut t).is_err());
+        let mut t = SliceString::new(&mut b1[..]);
+        t.push('x');
+        s.clone_into_slice_string(&mut t).unwrap();
+        assert_eq!(t, "aé");
     }
-}
 
-// SliceString<'a> == &'str
-impl<'a> PartialEq<&str> for SliceString<'a> {
-    fn eq(&self, other: &&str) -> bool {
-        str::eq(&self[..], &other[..])
+    #[test]
+    fn swap_content() {
+        let mut b1 = [0; 8];
+        let mut s1 = SliceString::new(&mut b1[..]);
+        s1.push_str("aé€");
+        let mut b2 = [0; 6];
+        let mut s2 = SliceString::new(&mut b2[..]);
+        s2.push_str("xy");
+        s1.swap_content(&mut s2).unwrap();
+        assert_eq!(s1, "xy");
+        assert_eq!(s2, "aé€");
+        s1.swap_content(&mut s2).unwrap();
+        assert_eq!(s1, "aé€");
+        assert_eq!(s2, "xy");
+        s1.push('z');
+        assert_eq!(
+            s2.swap_content(&mut s1),
+            Err(Error {
+                needed: 7,
+                available: 6
+            })
+        );
+        assert_eq!(s1, "aé€z");
+        assert_eq!(s2, "xy");
     }
-}
 
-// str == SliceString<'a>
-impl<'a> PartialEq<SliceString<'a>> for str {
-    fn eq(&self, other: &SliceString<'a>) -> bool {
-        str::eq(self, &other[..])
+    #[test]
+    fn prepend() {
+        let mut b = [0; 9];
+        let mut s = SliceString::new(&mut b[..]);
+        s.prepend("body").unwrap();
+        s.prepend("€:").unwrap();
+        assert_eq!(s, "€:body");
+        assert!(s.prepend("ab").is_err());
+        s.prepend("a").unwrap();
+        assert_eq!(s, "a€:body");
     }
-}
 
-// &'str == SliceString<'a>
-impl<'a> PartialEq<SliceString<'a>> for &str {
-    fn eq(&self, other: &SliceString<'a>) -> bool {
-        str::eq(&self[..], &other[..])
+    #[test]
+    fn overwrite_from() {
+        let mut b = [0; 4];
+        let mut s = SliceString::new(&mut b[..]);
+        s.overwrite_from("abcd").unwrap();
+        assert_eq!(s, "abcd");
+        assert_eq!(
+            s.overwrite_from("abcde"),
+            Err(Error {
+                needed: 5,
+                available: 4
+            })
+        );
+        assert!(s.is_empty());
     }
-}
 
-impl<'a> Eq for SliceString<'a> {}
+    #[test]
+    fn find_char() {
+        let mut b = "a€b€a".as_bytes().to_owned();
+        let s = SliceString::try_from(&mut b[..]).unwrap();
+        assert_eq!(s.find_char('x'), None);
+        assert_eq!(s.rfind_char('x'), None);
+        assert_eq!(s.find_char('b'), Some(4));
+        assert_eq!(s.rfind_char('b'), Some(4));
+        assert_eq!(s.find_char('€'), Some(1));
+        assert_eq!(s.rfind_char('€'), Some(5));
+        assert_eq!(s.find_char('a'), Some(0));
+        assert_eq!(s.rfind_char('a'), Some(8));
+    }
 
-impl<'a> PartialOrd for SliceString<'a> {
-    fn partial_cmp(&self, other: &SliceString<'a>) -> Option<core::cmp::Ordering> {
-        PartialOrd::partial_cmp(&**self, &**other)
+    #[test]
+    fn starts_with_char() {
+        let mut b = "€ab".as_bytes().to_owned();
+        let mut s = SliceString::try_from(&mut b[..]).unwrap();
+        assert!(s.starts_with_char('€'));
+        assert!(!s.starts_with_char('a'));
+        assert!(s.ends_with_char('b'));
+        assert!(!s.ends_with_char('€'));
+        s.clear();
+        assert!(!s.starts_with_char('€'));
+        assert!(!s.ends_with_char('b'));
     }
-}
 
-impl<'a> Ord for SliceString<'a> {
-    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
-        Ord::cmp(&**self, &**other)
+    #[test]
+    fn char_boundary() {
+        let mut b = "a€𝄞".as_bytes().to_owned();
+        let s = SliceString::try_from(&mut b[..]).unwrap();
+        let before: Vec<_> = (0..=9).map(|i| s.char_boundary_before(i)).collect();
+        assert_eq!(before, [0, 1, 1, 1, 4, 4, 4, 4, 8, 8]);
+        let after: Vec<_> = (0..=9).map(|i| s.char_boundary_after(i)).collect();
+        assert_eq!(after, [0, 1, 4, 4, 4, 8, 8, 8, 8, 8]);
     }
-}
 
-impl<'a> Extend<char> for SliceString<'a> {
-    fn extend<T: IntoIterator<Item = char>>(&mut self, iter: T) {
-        let iterator = iter.into_iter();
-        iterator.for_each(move |c| self.push(c));
+    #[test]
+    fn char_boundaries() {
+        let mut b = "a€𝄞".as_bytes().to_owned();
+        let mut s = SliceString::try_from(&mut b[..]).unwrap();
+        assert!(s.char_boundaries().eq([0, 1, 4, 8]));
+        assert!(s.char_boundaries().all(|i| s.is_char_boundary(i)));
+        s.clear();
+        assert!(s.char_boundaries().eq([0]));
     }
-}
 
-impl<'a> Extend<&'a char> for SliceString<'a> {
-    fn extend<I: IntoIterator<Item = &'a char>>(&mut self, iter: I) {
-        self.extend(iter.into_iter().cloned());
+    #[test]
+    fn concat() {
+        let mut b = [0; 6];
+        let s = SliceString::new(&mut b[..]);
+        let s = s + "foo";
+        let s = super::concat(s, "bar").unwrap();
+        assert_eq!(s, "foobar");
+        assert_eq!(
+            super::concat(s, "!").unwrap_err(),
+            Error {
+                needed: 1,
+                available: 0
+            }
+        );
     }
-}
 
-impl<'a> Extend<&'a str> for SliceString<'a> {
-    fn extend<T: IntoIterator<Item = &'a str>>(&mut self, iter: T) {
-        iter.into_iter().for_each(move |s| self.push_str(s));
+    #[test]
+    fn add_assign() {
+        let mut b = [0; 6];
+        let mut s = SliceString::new(&mut b[..]);
+        let suffix = String::from("bar");
+        s += "foo";
+        s += &suffix;
+        assert_eq!(s, "foobar");
     }
-}
 
-impl<'a> ops::Add<&'a str> for SliceString<'a> {
-    type Output = SliceString<'a>;
-    #[inline]
-    fn add(mut self, rhs: &str) -> Self::Output {
-        self.push_str(rhs);
-        self
+    #[test]
+    #[should_panic(expected = "SliceString full: needed 4, available 3")]
+    fn panic_add_assign() {
+        let mut b = [0; 6];
+        let mut s = SliceString::new(&mut b[..]);
+        s += "foo";
+        s += "barz";
     }
-}
 
-impl<'a> ops::AddAssign<&'a str> for SliceString<'a> {
-    #[inline]
-    fn add_assign(&mut self, rhs: &'a str) {
-        self.push_str(rhs);
+    #[test]
+    fn as_bytes() {
+        let mut b = [0; 4];
+        let mut s = SliceString::new(&mut b[..]);
+        s.push('é');
+        assert_eq!(s.as_bytes(), [0xc3, 0xa9]);
     }
-}
 
-// TODO: {Index,IndexMut}<{Range,RangeInclusive,RangeFrom,RangeTo,RangeToInclusive,RangeFull}>
+    #[test]
+    fn as_bytes_mut() {
+        let mut b = [0; 4];
+        let mut s = SliceString::new(&mut b[..]);
+        s.push_str("ab");
+        let r = unsafe { s.as_bytes_mut() };
+        assert_eq!(r.len(), 2);
+        r[0] = b'c';
+        assert_eq!(s, "cb");
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use core::fmt::Write;
+    #[test]
+    fn raw_parts() {
+        let mut b = [0; 4];
+        let mut s = SliceString::new(&mut b[..]);
+        s.push('é');
+        let (ptr, len, cap) = s.into_raw_parts();
+        assert_eq!((len, cap), (2, 4));
+        let mut s = unsafe { SliceString::from_raw_parts(ptr, len, cap) };
+        assert_eq!(s, "é");
+        s.push_str("ab");
+        assert!(s.is_full());
+    }
 
     #[test]
-    fn new() {
-        let mut buf = [0u8; 16];
-        let mut s = SliceString::new(&mut buf[..]);
-        assert_eq!(0, s.len());
-        assert_eq!(s.capacity(), 16);
+    fn cstr() {
+        let mut b = [0; 4];
+        let mut s = SliceString::new(&mut b[..]);
+        s.push_str("ab");
+        assert_eq!(s.as_cstr_after_nul(), None);
+        s.push_nul().unwrap();
+        assert_eq!(s.as_cstr_after_nul().unwrap().to_bytes(), b"ab");
+        s.push_nul().unwrap();
+        assert_eq!(s.as_cstr_after_nul(), None);
+        assert!(s.push_nul().is_err());
+    }
 
-        s.push_str("Hello world!");
-        assert_eq!(s.as_str(), "Hello world!");
+    #[test]
+    fn from_cstr() {
+        let mut b = [0; 4];
+        let cs = c"aé";
+        let s = SliceString::from_cstr(cs, &mut b[..]).unwrap();
+        assert_eq!(s, "aé");
+        let cs = c"abcde";
+        assert_eq!(
+            SliceString::from_cstr(cs, &mut b[..]),
+            Err(FromCStrError::Capacity(Error {
+                needed: 5,
+                available: 4
+            }))
+        );
+        let cs = c"\xff";
+        assert!(matches!(
+            SliceString::from_cstr(cs, &mut b[..]),
+            Err(FromCStrError::Utf8(_))
+        ));
+    }
 
-        assert!(!s.is_empty());
+    #[test]
+    fn encode_utf16_into() {
+        let mut b = "a€𝄞".as_bytes().to_owned();
+        let s = SliceString::try_from(&mut b[..]).unwrap();
+        let mut u = [0u16; 4];
+        assert_eq!(s.encode_utf16_into(&mut u), Ok(4));
+        assert_eq!(u, [0x61, 0x20ac, 0xd834, 0xdd1e]);
+        let mut u = [0u16; 3];
+        assert_eq!(
+            s.encode_utf16_into(&mut u),
+            Err(Error {
+                needed: 4,
+                available: 3
+            })
+        );
+        assert_eq!(u, [0; 3]);
+    }
+
+    #[test]
+    fn from_utf16() {
+        let mut b = [0; 8];
+        let u = [0x61, 0x20ac, 0xd834, 0xdd1e];
+        let s = SliceString::from_utf16(&mut b[..], &u).unwrap();
+        assert_eq!(s, "a€𝄞");
+        assert_eq!(
+            SliceString::from_utf16(&mut b[..7], &u),
+            Err(FromUtf16Error::Capacity(Error {
+                needed: 8,
+                available: 7
+            }))
+        );
+        let err = SliceString::from_utf16(&mut b[..], &[0x61, 0xdd1e]).unwrap_err();
+        match err {
+            FromUtf16Error::Utf16(e) => assert_eq!(e.unpaired_surrogate(), 0xdd1e),
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn cmp_str() {
+        let mut b = "abc".as_bytes().to_owned();
+        let s = SliceString::try_from(&mut b[..]).unwrap();
+        assert!(s < "abd");
+        assert!(s > "ab");
+        assert!(*"abd" > s);
+        assert!("ab" < s);
+        assert!(s <= *"abc");
+        assert_eq!(s.partial_cmp("abc"), Some(core::cmp::Ordering::Equal));
+    }
+
+    #[test]
+    fn to_ascii_case_into() {
+        let mut b = "aBéÉ1".as_bytes().to_owned();
+        let s = SliceString::try_from(&mut b[..]).unwrap();
+        let mut d = [0; 8];
+        let mut dst = SliceString::new(&mut d[..]);
+        s.to_ascii_uppercase_into(&mut dst).unwrap();
+        assert_eq!(dst, "ABéÉ1");
+        s.to_ascii_lowercase_into(&mut dst).unwrap();
+        assert_eq!(dst, "abéÉ1");
+        let mut d = [0; 6];
+        let mut dst = SliceString::new(&mut d[..]);
+        assert!(s.to_ascii_uppercase_into(&mut dst).is_err());
+        assert!(dst.is_empty());
+    }
+
+    #[test]
+    fn map_chars_into() {
+        let mut b = "Hello, é!".as_bytes().to_owned();
+        let s = SliceString::try_from(&mut b[..]).unwrap();
+        let mut d = [0; 10];
+        let mut dst = SliceString::new(&mut d[..]);
+        let rot13 = |c: char| match c {
+            'a'..='z' => ((c as u8 - b'a' + 13) % 26 + b'a') as char,
+            'A'..='Z' => ((c as u8 - b'A' + 13) % 26 + b'A') as char,
+            _ => c,
+        };
+        s.map_chars_into(&mut dst, rot13).unwrap();
+        assert_eq!(dst, "Uryyb, é!");
+        assert!(s.map_chars_into(&mut dst, |_| '€').is_err());
+        assert_eq!(dst, "€€€");
+    }
+
+    #[test]
+    fn filter_chars_into() {
+        let mut b = "a1é2€3".as_bytes().to_owned();
+        let s = SliceString::try_from(&mut b[..]).unwrap();
+        let mut d = [0; 4];
+        let mut dst = SliceString::new(&mut d[..]);
+        s.filter_chars_into(&mut dst, |c| c.is_ascii_digit())
+            .unwrap();
+        assert_eq!(dst, "123");
+        assert!(s
+            .filter_chars_into(&mut dst, |c| !c.is_ascii_digit())
+            .is_err());
+        assert_eq!(dst, "aé");
+    }
+
+    #[test]
+    fn char_replace_all() {
+        let mut b = [0; 16];
+        let mut s = SliceString::new(&mut b[..]);
+        s.push_str("a,b,c");
+        s.char_replace_all(',', ';').unwrap();
+        assert_eq!(s, "a;b;c");
+        s.char_replace_all(';', 'é').unwrap();
+        assert_eq!(s, "aébéc");
+        s.char_replace_all('é', '€').unwrap();
+        assert_eq!(s, "a€b€c");
+        s.char_replace_all('€', '𝄞').unwrap();
+        assert_eq!(s, "a𝄞b𝄞c");
+        s.char_replace_all('𝄞', '-').unwrap();
+        assert_eq!(s, "a-b-c");
+        s.char_replace_all('-', '𝄞').unwrap();
+        s.char_replace_all('𝄞', 'é').unwrap();
+        assert_eq!(s, "aébéc");
+        s.char_replace_all('é', ',').unwrap();
+        assert_eq!(s, "a,b,c");
+        s.char_replace_all('x', '𝄞').unwrap();
+        assert_eq!(s, "a,b,c");
         s.clear();
-        assert_eq!(s.len(), 0);
+        s.push_str("ab𝄞𝄞");
+        s.char_replace_all('𝄞', 'x').unwrap();
+        assert_eq!(s.len(), 4);
+        s.push_str("aaaaaaaa");
+        assert_eq!(
+            s.char_replace_all('a', 'é'),
+            Err(Error {
+                needed: 9,
+                available: 4
+            })
+        );
+        assert_eq!(s, "abxxaaaaaaaa");
+    }
 
-        s.push_str("foo");
-        s.truncate(2);
-        assert_eq!(s.len(), 2);
-        assert_eq!(s.as_str(), "fo");
+    #[test]
+    fn replace_str_into() {
+        let mut b = "aaa-é-aaa".as_bytes().to_owned();
+        let s = SliceString::try_from(&mut b[..]).unwrap();
+        let mut d = [0; 15];
+        let mut dst = SliceString::new(&mut d[..]);
+        s.replace_str_into("aa", "b", &mut dst).unwrap();
+        assert_eq!(dst, "ba-é-ba");
+        s.replace_str_into("é", "€€", &mut dst).unwrap();
+        assert_eq!(dst, "aaa-€€-aaa");
+        s.replace_str_into("x", "y", &mut dst).unwrap();
+        assert_eq!(dst, s);
+        s.replace_str_into("-", "", &mut dst).unwrap();
+        assert_eq!(dst, "aaaéaaa");
+        let mut b = "aé".as_bytes().to_owned();
+        let t = SliceString::try_from(&mut b[..]).unwrap();
+        t.replace_str_into("", "|", &mut dst).unwrap();
+        assert_eq!(dst, "|a|é|");
+        assert_eq!(
+            s.replace_str_into("a", "xy", &mut dst),
+            Err(Error {
+                needed: 2,
+                available: 1
+            })
+        );
+        assert_eq!(dst, "xyxyxy-é-xyxy");
+    }
 
-        s.push('r');
-        assert_eq!(s.as_str(), "for");
+    #[test]
+    fn split_at_newline() {
+        let mut b = *b"ab\ncd\r\n";
+        let s = SliceString::try_from(&mut b[..]).unwrap();
+        assert_eq!(s.split_at_newline(), Some(("ab", "cd\r\n")));
+        let mut b = *b"\r\n";
+        let s = SliceString::try_from(&mut b[..]).unwrap();
+        assert_eq!(s.split_at_newline(), Some(("", "")));
+        let mut b = *b"ab\r";
+        let s = SliceString::try_from(&mut b[..]).unwrap();
+        assert_eq!(s.split_at_newline(), None);
+    }
 
-        s.write_str("oooooooooooooooooooooo").unwrap_err();
+    #[test]
+    fn count() {
+        let mut b = "aaaä".as_bytes().to_owned();
+        let s = SliceString::try_from(&mut b[..]).unwrap();
+        assert_eq!(s.count_char('a'), 3);
+        assert_eq!(s.count_char('ä'), 1);
+        assert_eq!(s.count_char('b'), 0);
+        assert_eq!(s.count_occurrences("aa"), 1);
+        assert_eq!(s.count_occurrences("aaä"), 1);
+        assert_eq!(s.count_occurrences(""), 5);
+    }
 
-        let mut a = s.split_off(2);
-        assert_eq!(s.as_str(), "fo");
-        assert_eq!(a.as_str(), "r");
+    #[test]
+    fn send_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<SliceString<'static>>();
+        assert_send_sync::<Error>();
+        assert_send_sync::<Drain<'static, 'static>>();
+        assert_send_sync::<Lines<'static>>();
+        assert_send_sync::<Words<'static>>();
+    }
 
-        a.push_str("ab");
-        s.push_str("");
-        s.write_char('o').unwrap_err();
-        assert_eq!(s.capacity(), 2);
-        assert_eq!(a.capacity(), 16 - 2);
+    #[test]
+    fn pad() {
+        let mut buf = [0; 11];
+        let mut s = SliceString::new(&mut buf[..]);
+        s.push_str("ä");
+        s.pad_right(3, '-').unwrap();
+        assert_eq!(s, "ä--");
+        s.pad_left(5, '€').unwrap();
+        assert_eq!(s, "€€ä--");
+        s.pad_right(2, '-').unwrap();
+        s.pad_left(5, '-').unwrap();
+        assert_eq!(s, "€€ä--");
+        assert_eq!(
+            s.pad_left(6, '€'),
+            Err(Error {
+                needed: 3,
+                available: 1
+            })
+        );
+        assert_eq!(
+            s.pad_right(7, '-'),
+            Err(Error {
+                needed: 2,
+                available: 1
+            })
+        );
+        assert_eq!(s, "€€ä--");
+        s.pad_right(6, '-').unwrap();
+        assert_eq!(s, "€€ä---");
+    }
 
-        let r = unsafe { s.as_mut_slicevec() };
-        assert_eq!(r.as_ref(), "fo".as_bytes());
+    #[test]
+    fn center() {
+        let mut buf = [0; 12];
+        let mut s = SliceString::new(&mut buf[..]);
+        s.push_str("ä");
+        s.center(2, '-').unwrap();
+        assert_eq!(s, "ä-");
+        s.center(1, '-').unwrap();
+        assert_eq!(s, "ä-");
+        s.center(4, '-').unwrap();
+        assert_eq!(s, "-ä--");
+        assert_eq!(
+            s.center(7, '€'),
+            Err(Error {
+                needed: 9,
+                available: 7
+            })
+        );
+        assert_eq!(s, "-ä--");
+        s.center(9, ' ').unwrap();
+        assert_eq!(s, "  -ä--   ");
+    }
 
-        assert_eq!(s.pop().unwrap(), 'o');
-        assert_eq!(s.pop().unwrap(), 'f');
+    #[test]
+    fn deduplicate_char() {
+        let mut b = "ääää".as_bytes().to_owned();
+        let mut s = SliceString::try_from(&mut b[..]).unwrap();
+        s.deduplicate_char('ä');
+        assert_eq!(s, "ä");
+        let mut b = "aäbä".as_bytes().to_owned();
+        let mut s = SliceString::try_from(&mut b[..]).unwrap();
+        s.deduplicate_char('ä');
+        assert_eq!(s, "aäbä");
+        let mut b = "ääxää€€ä".as_bytes().to_owned();
+        let mut s = SliceString::try_from(&mut b[..]).unwrap();
+        s.deduplicate_char('ä');
+        assert_eq!(s, "äxä€€ä");
     }
 
     #[test]
-    #[should_panic]
-    fn panic_push() {
-        let mut buf = [0u8; 1];
+    fn normalize_whitespace() {
+        let mut b = "a\u{3000}\u{3000}b \t\nc".as_bytes().to_owned();
+        let mut s = SliceString::try_from(&mut b[..]).unwrap();
+        s.normalize_whitespace();
+        assert_eq!(s, "a b c");
+        let mut b = *b"abc";
+        let mut s = SliceString::try_from(&mut b[..]).unwrap();
+        s.normalize_whitespace();
+        assert_eq!(s, "abc");
+        let mut b = *b" \t\r\n ";
+        let mut s = SliceString::try_from(&mut b[..]).unwrap();
+        s.normalize_whitespace();
+        assert_eq!(s, "");
+        let mut b = "\u{3000} ä  ö\n".as_bytes().to_owned();
+        let mut s = SliceString::try_from(&mut b[..]).unwrap();
+        s.normalize_whitespace();
+        assert_eq!(s, "ä ö");
+    }
+
+    #[test]
+    fn from_str_in_place() {
+        let mut buf = [0; 3];
+        let s = SliceString::from_str_in_place("ä", &mut buf[..]).unwrap();
+        assert_eq!(s, "ä");
+        assert_eq!(s.capacity(), 3);
+        assert_eq!(
+            SliceString::from_str_in_place("äb", &mut buf[..2]),
+            Err(Error {
+                needed: 3,
+                available: 2
+            })
+        );
+    }
+
+    #[test]
+    fn try_push_str_partial() {
+        let mut buf = [0; 4];
         let mut s = SliceString::new(&mut buf[..]);
-        s.push('f');
-        s.push('o');
+        assert_eq!(s.try_push_str_partial("aä"), ("aä", ""));
+        assert_eq!(s.try_push_str_partial("äb"), ("", "äb"));
+        assert_eq!(s.try_push_str_partial("b€"), ("b", "€"));
+        assert_eq!(s, "aäb");
+        assert_eq!(s.try_push_str_partial(""), ("", ""));
     }
 
     #[test]
-    #[should_panic]
-    fn panic_push_str() {
-        let mut buf = [0u8; 1];
+    fn string_buf() {
+        fn fill<S: StringBuf>(s: &mut S) -> Result<(), Error> {
+            StringBuf::clear(s);
+            StringBuf::push(s, 'ä')?;
+            StringBuf::push_str(s, "bc")
+        }
+        let mut buf = [0; 4];
         let mut s = SliceString::new(&mut buf[..]);
-        s.push_str("fo");
+        fill(&mut s).unwrap();
+        assert_eq!(s, "äbc");
+        assert_eq!(StringBuf::remaining_capacity(&s), 0);
+        assert_eq!(
+            StringBuf::push(&mut s, 'd'),
+            Err(Error {
+                needed: 1,
+                available: 0
+            })
+        );
+        let mut t = StackString::<3>::new();
+        assert_eq!(
+            fill(&mut t),
+            Err(Error {
+                needed: 2,
+                available: 1
+            })
+        );
+        assert_eq!(t, "ä");
     }
 
     #[test]
-    fn cmp() {
-        let mut b1 = "abcd".as_bytes().to_owned();
-        let s1 = SliceString::try_from(&mut b1[..]).unwrap();
-        let mut b2 = "zzzz".as_bytes().to_owned();
-        let s2 = SliceString::try_from(&mut b2[..]).unwrap();
-        assert!(s1 < s2);
+    fn hash_map_key() {
+        use std::collections::HashMap;
+
+        let mut a = *b"ab";
+        let mut b = [0; 4];
+        let mut m = HashMap::new();
+        m.insert(SliceString::try_from(&mut a[..]).unwrap(), 1);
+        m.insert(SliceString::try_from_str("äb", &mut b[..]).unwrap(), 2);
+        assert_eq!(m.get("ab"), Some(&1));
+        assert_eq!(m.get("äb"), Some(&2));
+        assert_eq!(m.get("a"), None);
+        let mut c = *b"ab";
+        let key = SliceString::try_from(&mut c[..]).unwrap();
+        assert_eq!(m.get(&key), Some(&1));
     }
 
     #[test]
-    fn disp() {
-        let mut b1 = "abcd".as_bytes().to_owned();
-        let s1 = SliceString::try_from(&mut b1[..]).unwrap();
-        let mut s = String::new();
-        write!(s, "{}", s1).unwrap();
-        assert_eq!("abcd", s);
+    fn as_str_limited() {
+        let mut b = "aä€".as_bytes().to_owned();
+        let s = SliceString::try_from(&mut b[..]).unwrap();
+        assert_eq!(s.as_str_limited(0), "");
+        assert_eq!(s.as_str_limited(2), "a");
+        assert_eq!(s.as_str_limited(3), "aä");
+        assert_eq!(s.as_str_limited(5), "aä");
+        assert_eq!(s.as_str_limited(6), "aä€");
+        assert_eq!(s.as_str_limited(usize::MAX), "aä€");
     }
 
     #[test]
-    fn pop_uenc() {
-        let mut b = "éé".as_bytes().to_owned();
-        assert_eq!(b.len(), 2 + 3);
+    fn chars_equal() {
+        let mut b = "aä".as_bytes().to_owned();
+        let s = SliceString::try_from(&mut b[..]).unwrap();
+        assert!(s.chars_equal("aä"));
+        assert!(!s.chars_equal("a"));
+        assert!(!s.chars_equal("aä "));
+        assert!(!s.chars_equal("a\u{308}a"));
+        assert!(!s.chars_equal("\u{feff}aä"));
+    }
+
+    #[test]
+    fn truncate_chars() {
+        let mut b = "aä€".as_bytes().to_owned();
         let mut s = SliceString::try_from(&mut b[..]).unwrap();
-        assert_eq!(s.len(), 2 + 3);
-        assert_eq!(s.pop().unwrap(), '\u{0301}');
-        assert_eq!(s.len(), 2 + 1);
-        assert_eq!(s.pop().unwrap(), 'e');
-        assert_eq!(s.len(), 2);
-        assert_eq!(s.pop().unwrap(), 'é');
-        assert_eq!(s.len(), 0);
-        s.push('ö');
-        s.push_str("ü");
-        assert_eq!(s.as_str(), "öü");
+        s.truncate_chars(4);
+        assert_eq!(s, "aä€");
+        s.truncate_chars(3);
+        assert_eq!(s, "aä€");
+        s.truncate_chars(2);
+        assert_eq!(s, "aä");
+        s.truncate_chars(0);
+        assert_eq!(s, "");
+        s.truncate_chars(1);
+        assert_eq!(s, "");
     }
 
     #[test]
-    fn write() {
-        let mut b = [0; 8];
-        let mut s = SliceString::new(&mut b[..]);
-        s.write_str("a").unwrap();
-        s.write_char('b').unwrap();
-        write!(s, "cdefgh").unwrap();
-        assert_eq!(s.len(), 8);
-        write!(s, "").unwrap();
-        write!(s, "a").unwrap_err();
+    fn char_len_for_bytes() {
+        let mut b = "aä€".as_bytes().to_owned();
+        let s = SliceString::try_from(&mut b[..]).unwrap();
+        let n: [usize; 8] = core::array::from_fn(|i| s.char_len_for_bytes(i));
+        assert_eq!(n, [0, 1, 1, 2, 2, 2, 3, 3]);
+        assert_eq!(s.char_len_for_bytes(usize::MAX), 3);
     }
 
     #[test]
-    fn extend() {
-        let mut b = [0; 8];
-        let q = ["foo"];
-        let mut i = q.iter().copied();
-        let ii = &mut i;
-        {
-            let mut s = SliceString::new(&mut b[..]);
-            s.extend(ii);
-            println!("{}", s + "d");
-        }
-        println!("{:?}", i.next());
-        println!("{}", q[0])
+    fn split_lines_into() {
+        let mut b = "ä\n\r\nb\r".as_bytes().to_owned();
+        let s = SliceString::try_from(&mut b[..]).unwrap();
+        let mut out: [SliceString<'_>; 4] = Default::default();
+        assert_eq!(s.split_lines_into(&mut out), 3);
+        assert_eq!(out, ["ä", "", "b\r", ""]);
+        assert_eq!(out[0].capacity(), 2);
+        let mut b = *b"a\nb\n";
+        let s = SliceString::try_from(&mut b[..]).unwrap();
+        let mut out: [SliceString<'_>; 1] = Default::default();
+        assert_eq!(s.split_lines_into(&mut out), 1);
+        assert_eq!(out, ["a"]);
+        let mut b = *b"\n";
+        let s = SliceString::try_from(&mut b[..]).unwrap();
+        let mut out: [SliceString<'_>; 2] = Default::default();
+        assert_eq!(s.split_lines_into(&mut out), 1);
+        let mut out: [SliceString<'_>; 2] = Default::default();
+        let s = SliceString::new(&mut []);
+        assert_eq!(s.split_lines_into(&mut out), 0);
+    }
+
+    #[test]
+    fn join_into() {
+        let mut buf = [0; 6];
+        let mut s = SliceString::new(&mut buf[..]);
+        super::join_into(&["a", "ä"], ", ", &mut s).unwrap();
+        assert_eq!(s, "a, ä");
+        super::join_into(&["b"], ", ", &mut s).unwrap();
+        assert_eq!(s, "b");
+        super::join_into(&[], ", ", &mut s).unwrap();
+        assert_eq!(s, "");
+        assert_eq!(
+            super::join_into(&["a", "b", "c"], ", ", &mut s),
+            Err(Error {
+                needed: 7,
+                available: 6
+            })
+        );
+        assert_eq!(s, "");
+        concat_into(&["ab", "", "cä"], &mut s).unwrap();
+        assert_eq!(s, "abcä");
+        s.push('x');
+        assert_eq!(
+            concat_into(&["abc", "ä€"], &mut s),
+            Err(Error {
+                needed: 8,
+                available: 6
+            })
+        );
+        assert_eq!(s, "");
+    }
+
+    #[test]
+    fn parse_from_str() {
+        let mut b = *b"-12";
+        let s = SliceString::try_from(&mut b[..]).unwrap();
+        assert_eq!(super::parse_from_str::<i8>(&s), Ok(-12));
+        assert!(super::parse_from_str::<u8>(&s).is_err());
+        let mut b = *b"2";
+        let s = SliceString::try_from(&mut b[..]).unwrap();
+        assert_eq!(super::parse_from_str::<char>(&s), Ok('2'));
+    }
+
+    #[test]
+    fn try_split_off() {
+        let mut buf = [0; 6];
+        let mut s = SliceString::try_from_str("aä", &mut buf[..]).unwrap();
+        assert_eq!(s.try_split_off(4), Err(BoundaryError { index: 4 }));
+        assert_eq!(s.try_split_off(2), Err(BoundaryError { index: 2 }));
+        let t = s.try_split_off(3).unwrap();
+        assert_eq!((t.len(), t.capacity()), (0, 3));
+        let t = s.try_split_off(1).unwrap();
+        assert_eq!((t.as_str(), t.capacity()), ("ä", 2));
+        assert_eq!(s, "a");
+        assert_eq!(s.capacity(), 1);
+    }
+
+    #[test]
+    fn try_truncate() {
+        let mut b = "aä".as_bytes().to_owned();
+        let mut s = SliceString::try_from(&mut b[..]).unwrap();
+        assert_eq!(s.try_truncate(4), Err(BoundaryError { index: 4 }));
+        assert_eq!(s.try_truncate(2), Err(BoundaryError { index: 2 }));
+        assert_eq!(s, "aä");
+        assert_eq!(s.try_truncate(3), Ok(()));
+        assert_eq!(s.try_truncate(1), Ok(()));
+        assert_eq!(s, "a");
+        assert_eq!(s.try_truncate(0), Ok(()));
+        assert_eq!(s, "");
+    }
+
+    #[test]
+    fn iter_chunks() {
+        let mut b = "aä€b".as_bytes().to_owned();
+        let s = SliceString::try_from(&mut b[..]).unwrap();
+        assert!(s.iter_chunks(1).eq(["a", "ä", "€", "b"]));
+        assert!(s.iter_chunks(2).eq(["a", "ä", "€", "b"]));
+        assert!(s.iter_chunks(3).eq(["aä", "€", "b"]));
+        assert!(s.iter_chunks(4).eq(["aä", "€b"]));
+        assert!(s.iter_chunks(7).eq(["aä€b"]));
+        assert!(s.iter_chunks(100).eq(["aä€b"]));
+        let s = SliceString::new(&mut []);
+        assert_eq!(s.iter_chunks(1).next(), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panic_iter_chunks() {
+        let mut b = *b"a";
+        let s = SliceString::try_from(&mut b[..]).unwrap();
+        let _ = s.iter_chunks(0);
+    }
+
+    #[test]
+    fn encode_latin1_lossy_into() {
+        let mut b = "A~ \u{7f}\u{a0}äÿ\u{100}€😀".as_bytes().to_owned();
+        let s = SliceString::try_from(&mut b[..]).unwrap();
+        let mut dst = [0; 12];
+        assert_eq!(s.encode_latin1_lossy_into(&mut dst), 10);
+        assert_eq!(&dst[..10], b"A~ \x7f\xa0\xe4\xff???");
+        assert_eq!(s.encode_latin1_lossy_into(&mut dst[..3]), 3);
+        assert_eq!(s.encode_latin1_lossy_into(&mut []), 0);
     }
 }
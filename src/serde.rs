@@ -0,0 +1,72 @@
+use core::fmt;
+
+use serde::de::{self, DeserializeSeed, Deserializer, Visitor};
+use serde::{Serialize, Serializer};
+
+use crate::SliceString;
+
+impl Serialize for SliceString<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self)
+    }
+}
+
+/// Deserialize a string into the buffer of a `SliceString`.
+///
+/// The existing content is replaced. Deserialization fails if the string does not fit.
+impl<'de, 'a> DeserializeSeed<'de> for SliceString<'a> {
+    type Value = SliceString<'a>;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_str(SliceStringVisitor(self))
+    }
+}
+
+/// Copy a visited string into the buffer of the wrapped `SliceString`.
+struct SliceStringVisitor<'a>(SliceString<'a>);
+
+impl<'de, 'a> Visitor<'de> for SliceStringVisitor<'a> {
+    type Value = SliceString<'a>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "a string of at most {} bytes", self.0.capacity())
+    }
+
+    fn visit_str<E: de::Error>(mut self, v: &str) -> Result<Self::Value, E> {
+        if self.0.copy_from_str(v).is_err() {
+            return Err(E::invalid_length(v.len(), &self));
+        }
+        Ok(self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::de::DeserializeSeed;
+
+    use crate::SliceString;
+
+    #[test]
+    fn serialize() {
+        let mut buf = *b"a\"b";
+        let s = SliceString::try_from(&mut buf[..]).unwrap();
+        assert_eq!(serde_json::to_string(&s).unwrap(), r#""a\"b""#);
+    }
+
+    #[test]
+    fn deserialize() {
+        let mut buf = [0u8; 4];
+        let s = SliceString::new(&mut buf[..]);
+        let mut de = serde_json::Deserializer::from_str(r#""aé""#);
+        let s = s.deserialize(&mut de).unwrap();
+        assert_eq!(s, "aé");
+    }
+
+    #[test]
+    fn deserialize_err() {
+        let mut buf = [0u8; 4];
+        let s = SliceString::new(&mut buf[..]);
+        let mut de = serde_json::Deserializer::from_str(r#""abcde""#);
+        assert!(s.deserialize(&mut de).is_err());
+    }
+}
@@ -0,0 +1,341 @@
+use core::{borrow, cmp, fmt, hash, ops, str};
+
+use crate::{Error, SliceString, StringBuf};
+
+/// A UTF-8-encoded string owning a fixed capacity buffer of `N` bytes.
+///
+/// [`SliceString`] borrows its buffer and can therefore not be cloned or stored
+/// without its backing storage. `StackString` owns the buffer instead and
+/// is `Clone`, `Copy`, and `Default`.
+///
+/// The basic string operations are available directly. The complete [`SliceString`]
+/// API is available through [`StackString::edit`] which borrows the buffer as a
+/// [`SliceString`] for the duration of a closure.
+///
+/// ```
+/// # use slice_string::StackString;
+/// let mut s = StackString::<16>::new();
+/// s.push_str("dt/");
+/// s.edit(|s| s.push_uint(42, 10)).unwrap();
+/// let t = s;
+/// assert_eq!(t, "dt/42");
+/// assert_eq!(t.capacity(), 16);
+/// ```
+#[derive(Copy, Clone)]
+pub struct StackString<const N: usize> {
+    buf: [u8; N],
+    len: usize,
+}
+
+impl<const N: usize> StackString<N> {
+    /// Create a new empty `StackString`.
+    pub const fn new() -> Self {
+        Self {
+            buf: [0; N],
+            len: 0,
+        }
+    }
+
+    /// Borrow the buffer as a [`SliceString`] and call `f` with it.
+    ///
+    /// Changes made through the `SliceString` are retained.
+    /// Should `f` panic, the string is left empty.
+    ///
+    /// # Panics
+    /// `f` must not replace the `SliceString` with one backed by a different buffer
+    /// or by only a part of the buffer. The string is left empty in that case.
+    pub fn edit<R>(&mut self, f: impl FnOnce(&mut SliceString<'_>) -> R) -> R {
+        let len = core::mem::take(&mut self.len);
+        let ptr = self.buf.as_ptr();
+        // `buf[..len]` is valid UTF-8
+        let mut s = unsafe { SliceString::from_utf8_unchecked(&mut self.buf[..], len) };
+        let ret = f(&mut s);
+        // Only trust the length if `s` still covers exactly `buf`.
+        assert!(
+            core::ptr::eq(s.as_ptr(), ptr) && s.capacity() == N,
+            "SliceString replaced in StackString::edit"
+        );
+        self.len = s.len();
+        ret
+    }
+
+    /// Return a reference to a UTF-8 `str`.
+    pub fn as_str(&self) -> &str {
+        // `buf[..len]` is valid UTF-8
+        unsafe { str::from_utf8_unchecked(&self.buf[..self.len]) }
+    }
+
+    /// Return a mutable reference to a UTF-8 `str`.
+    pub fn as_mut_str(&mut self) -> &mut str {
+        // `buf[..len]` is valid UTF-8
+        unsafe { str::from_utf8_unchecked_mut(&mut self.buf[..self.len]) }
+    }
+
+    /// Return the maximum number of bytes this string can contain.
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Return the number of bytes that can still be appended.
+    pub fn remaining_capacity(&self) -> usize {
+        N - self.len
+    }
+
+    /// Return whether no more bytes can be appended.
+    pub fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    /// Set the current string length to zero.
+    pub fn clear(&mut self) {
+        self.len = 0;
+    }
+
+    /// Truncate the string to the given byte length.
+    ///
+    /// # Panics
+    /// The new length must be at a character boundary.
+    pub fn truncate(&mut self, new_len: usize) {
+        self.edit(|s| s.truncate(new_len))
+    }
+
+    /// Return the last `char` in the string, or `None` if empty.
+    pub fn pop(&mut self) -> Option<char> {
+        self.edit(|s| s.pop())
+    }
+
+    /// Append a `char` to the string.
+    ///
+    /// # Panics
+    /// The remaining space must be sufficient.
+    pub fn push(&mut self, c: char) {
+        self.edit(|s| s.push(c))
+    }
+
+    /// Append a `str` to the string.
+    ///
+    /// # Panics
+    /// The remaining space must be sufficient.
+    pub fn push_str(&mut self, string: &str) {
+        self.edit(|s| s.push_str(string))
+    }
+}
+
+impl<const N: usize> Default for StackString<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> TryFrom<&str> for StackString<N> {
+    type Error = Error;
+
+    fn try_from(src: &str) -> Result<Self, Error> {
+        let mut s = Self::new();
+        s.edit(|s| s.copy_from_str(src))?;
+        Ok(s)
+    }
+}
+
+impl<const N: usize> StringBuf for StackString<N> {
+    fn push(&mut self, c: char) -> Result<(), Error> {
+        self.edit(|s| StringBuf::push(s, c))
+    }
+
+    fn push_str(&mut self, string: &str) -> Result<(), Error> {
+        self.edit(|s| StringBuf::push_str(s, string))
+    }
+
+    fn clear(&mut self) {
+        StackString::clear(self)
+    }
+
+    fn capacity(&self) -> usize {
+        N
+    }
+}
+
+impl<const N: usize> ops::Deref for StackString<N> {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> ops::DerefMut for StackString<N> {
+    fn deref_mut(&mut self) -> &mut str {
+        self.as_mut_str()
+    }
+}
+
+impl<const N: usize> AsRef<str> for StackString<N> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> AsMut<str> for StackString<N> {
+    fn as_mut(&mut self) -> &mut str {
+        self.as_mut_str()
+    }
+}
+
+impl<const N: usize> AsRef<[u8]> for StackString<N> {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl<const N: usize> borrow::Borrow<str> for StackString<N> {
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> fmt::Write for StackString<N> {
+    fn write_str(&mut self, s: &str) -> Result<(), fmt::Error> {
+        self.edit(|st| st.write_str(s))
+    }
+
+    fn write_char(&mut self, c: char) -> Result<(), fmt::Error> {
+        self.edit(|st| st.write_char(c))
+    }
+}
+
+impl<const N: usize> fmt::Debug for StackString<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl<const N: usize> fmt::Display for StackString<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.as_str(), f)
+    }
+}
+
+impl<const N: usize> hash::Hash for StackString<N> {
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state)
+    }
+}
+
+impl<const N: usize, const M: usize> PartialEq<StackString<M>> for StackString<N> {
+    fn eq(&self, other: &StackString<M>) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl<const N: usize> Eq for StackString<N> {}
+
+impl<const N: usize> PartialOrd for StackString<N> {
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const N: usize> Ord for StackString<N> {
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+impl<const N: usize> PartialEq<str> for StackString<N> {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl<const N: usize> PartialEq<&str> for StackString<N> {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl<'a, const N: usize> PartialEq<SliceString<'a>> for StackString<N> {
+    fn eq(&self, other: &SliceString<'a>) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl<'a, const N: usize> PartialEq<StackString<N>> for SliceString<'a> {
+    fn eq(&self, other: &StackString<N>) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::fmt::Write;
+
+    #[test]
+    fn stack_string() {
+        let mut s = StackString::<4>::default();
+        assert_eq!(s.capacity(), 4);
+        s.push('ä');
+        s.push_str("b");
+        assert_eq!(s, "äb");
+        let t = s;
+        s.clear();
+        assert_eq!(t, "äb");
+        assert_eq!(s, "");
+        assert!(write!(s, "{}", 123).is_ok());
+        assert!(write!(s, "{}", 45).is_err());
+        assert_eq!(s.remaining_capacity(), 1);
+        assert_eq!(s.pop(), Some('3'));
+        s.truncate(1);
+        s.make_ascii_uppercase();
+        assert_eq!(s.as_str(), "1");
+        assert!(!s.is_full());
+        assert_eq!(s.edit(|s| s.push_char_n('x', 3)), Ok(3));
+        assert!(s.is_full());
+        assert_eq!(
+            StackString::<2>::try_from("ä").unwrap(),
+            StackString::<4>::try_from("ä").unwrap()
+        );
+        assert_eq!(
+            StackString::<2>::try_from("äb"),
+            Err(Error {
+                needed: 3,
+                available: 2
+            })
+        );
+        let mut b = *b"1xxx";
+        assert_eq!(SliceString::try_from(&mut b[..]).unwrap(), s);
+    }
+
+    #[test]
+    fn edit_panic() {
+        let mut s = StackString::<4>::try_from("ab").unwrap();
+        let r = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            s.edit(|s| s.retain(|_| panic!()))
+        }));
+        assert!(r.is_err());
+        assert_eq!(s, "");
+    }
+
+    #[test]
+    fn edit_replace() {
+        let mut s = StackString::<4>::try_from("é").unwrap();
+        let leaked: &'static mut [u8] = Box::leak(Box::new(*b"\xc3\xa9ab"));
+        let r = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            s.edit(move |s| *s = SliceString::from_utf8(leaked, 1).unwrap())
+        }));
+        assert!(r.is_err());
+        assert_eq!(s, "");
+        assert_eq!(s.as_bytes(), b"");
+        let mut s = StackString::<4>::try_from("ab").unwrap();
+        let r = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            s.edit(|s| {
+                s.split_off_front(1);
+            })
+        }));
+        assert!(r.is_err());
+        assert_eq!(s, "");
+        let mut s = StackString::<4>::try_from("ab").unwrap();
+        s.edit(|s| *s = s.split_off(0));
+        assert_eq!(s, "ab");
+    }
+}
@@ -0,0 +1,135 @@
+use core::{borrow, fmt, ops};
+
+use crate::SliceString;
+
+/// A read-only string view that can be constructed in `const` context.
+///
+/// [`SliceString`] needs a `&mut [u8]` which is not available at compile time.
+/// `StaticSliceStr` wraps a `&str` instead, e.g. a literal placed in flash (`.rodata`),
+/// and dereferences to `str`. It compares equal to a [`SliceString`] with the same content.
+///
+/// ```
+/// # use slice_string::{SliceString, StaticSliceStr};
+/// const PREFIX: StaticSliceStr = StaticSliceStr::new("dt/sinara");
+/// let mut buf = [0; 32];
+/// let mut topic = SliceString::new(&mut buf[..]);
+/// topic.push_str(&PREFIX);
+/// assert_eq!(topic, PREFIX);
+/// ```
+#[derive(Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct StaticSliceStr<'a>(&'a str);
+
+impl<'a> StaticSliceStr<'a> {
+    /// Create a new view of a `str`.
+    pub const fn new(s: &'a str) -> Self {
+        Self(s)
+    }
+
+    /// Extract a string slice containing the entire view.
+    pub const fn as_str(&self) -> &'a str {
+        self.0
+    }
+
+    /// Return the length of the view in bytes.
+    pub const fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Return whether the view is empty.
+    pub const fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl<'a> From<&'a str> for StaticSliceStr<'a> {
+    fn from(s: &'a str) -> Self {
+        Self(s)
+    }
+}
+
+impl<'a> ops::Deref for StaticSliceStr<'a> {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.0
+    }
+}
+
+impl<'a> AsRef<str> for StaticSliceStr<'a> {
+    fn as_ref(&self) -> &str {
+        self.0
+    }
+}
+
+impl<'a> AsRef<[u8]> for StaticSliceStr<'a> {
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_bytes()
+    }
+}
+
+impl<'a> borrow::Borrow<str> for StaticSliceStr<'a> {
+    fn borrow(&self) -> &str {
+        self.0
+    }
+}
+
+impl<'a> fmt::Debug for StaticSliceStr<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.0, f)
+    }
+}
+
+impl<'a> fmt::Display for StaticSliceStr<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.0, f)
+    }
+}
+
+impl<'a> PartialEq<str> for StaticSliceStr<'a> {
+    fn eq(&self, other: &str) -> bool {
+        self.0 == other
+    }
+}
+
+impl<'a> PartialEq<&str> for StaticSliceStr<'a> {
+    fn eq(&self, other: &&str) -> bool {
+        self.0 == *other
+    }
+}
+
+impl<'a, 'b> PartialEq<SliceString<'b>> for StaticSliceStr<'a> {
+    fn eq(&self, other: &SliceString<'b>) -> bool {
+        self.0 == other.as_str()
+    }
+}
+
+impl<'a, 'b> PartialEq<StaticSliceStr<'b>> for SliceString<'a> {
+    fn eq(&self, other: &StaticSliceStr<'b>) -> bool {
+        self.as_str() == other.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const S: StaticSliceStr<'static> = StaticSliceStr::new("äb");
+    const LEN: usize = S.len();
+
+    #[test]
+    fn static_str() {
+        assert_eq!(LEN, 3);
+        assert!(!S.is_empty());
+        assert!(StaticSliceStr::default().is_empty());
+        assert_eq!(S, "äb");
+        assert_eq!(S.as_str(), "äb");
+        assert_eq!(S.chars().count(), 2);
+        let mut b = "äb".as_bytes().to_owned();
+        let mut s = SliceString::try_from(&mut b[..]).unwrap();
+        assert_eq!(s, S);
+        assert_eq!(S, s);
+        s.pop();
+        assert_ne!(s, S);
+        assert_eq!(StaticSliceStr::from("ä"), s);
+    }
+}